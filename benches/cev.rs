@@ -1,6 +1,6 @@
 #![feature(test)]
 extern crate test;
-use cev::cev::Cev;
+use cev::Cev;
 use test::{black_box, Bencher};
 
 const LEN: usize = 16384;
@@ -67,6 +67,52 @@ fn bench_push_1000(b: &mut Bencher) {
     bench_push(b, 1000)
 }
 
+fn do_bench_push_pop(b: &mut Bencher, src_len: usize) {
+    b.bytes = src_len as u64;
+    let mut cev = Cev::with_capacity(src_len);
+
+    b.iter(|| {
+        for i in 0..src_len {
+            cev.push(i);
+        }
+        while let Some(elem) = cev.pop() {
+            black_box(elem);
+        }
+    })
+}
+
+#[bench]
+fn bench_push_pop_0010(b: &mut Bencher) {
+    do_bench_push_pop(b, 10)
+}
+
+#[bench]
+fn bench_push_pop_1000(b: &mut Bencher) {
+    do_bench_push_pop(b, 1000)
+}
+
+fn do_bench_push_grow(b: &mut Bencher, src_len: usize) {
+    b.bytes = src_len as u64;
+
+    b.iter(|| {
+        let mut cev = Cev::new();
+        for i in 0..src_len {
+            cev.push(i as u8);
+        }
+        cev
+    })
+}
+
+#[bench]
+fn bench_push_grow_016384(b: &mut Bencher) {
+    do_bench_push_grow(b, LEN)
+}
+
+#[bench]
+fn bench_push_grow_1048576(b: &mut Bencher) {
+    do_bench_push_grow(b, 1 << 20)
+}
+
 fn do_bench_from_fn(b: &mut Bencher, src_len: usize) {
     b.bytes = src_len as u64;
 
@@ -93,6 +139,20 @@ fn bench_from_fn_1000(b: &mut Bencher) {
     do_bench_from_fn(b, 1000)
 }
 
+#[bench]
+fn bench_from_iter_filter_1000(b: &mut Bencher) {
+    b.bytes = 1000;
+
+    b.iter(|| (0..2000).filter(|x| x % 2 == 0).collect::<Cev<_>>())
+}
+
+#[bench]
+fn bench_from_iter_rev_1000(b: &mut Bencher) {
+    b.bytes = 1000;
+
+    b.iter(|| Cev::from_iter_rev((0..2000).filter(|x| x % 2 == 0).rev()))
+}
+
 #[bench]
 fn bench_flat_map_collect(b: &mut Bencher) {
     let v = Cev::from([777u32; 500000]);
@@ -108,10 +168,11 @@ fn bench_map_fast(b: &mut Bencher) {
     let data = black_box([(0, 0); LEN]);
     b.iter(|| {
         let mut result: Cev<u32> = Cev::with_capacity(data.len());
+        let end = result.as_mut_ptr();
         for i in 0..data.len() {
             unsafe {
-                *result.as_mut_ptr().sub(i) = data[i].0;
-                result.set_len(i);
+                *end.sub(i + 1) = data[i].0;
+                result.set_len(i + 1);
             }
         }
         result
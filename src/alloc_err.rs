@@ -1,33 +1,34 @@
-use std::alloc::{Layout, LayoutError};
-use std::error::Error;
-use std::fmt::{self, Display};
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
-pub struct AllocError;
-
-impl Error for AllocError {}
-
-impl fmt::Display for AllocError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("memory allocation failed")
-    }
-}
+use core::alloc::{Layout, LayoutError};
+use core::fmt::Display;
 
+/// The error type for `try_reserve` methods.
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct TryReserveError {
     kind: TryReserveErrorKind,
 }
 
 impl TryReserveError {
+    /// Details about the allocation that caused the error.
     pub fn kind(&self) -> TryReserveErrorKind {
         self.kind.clone()
     }
 }
 
+/// Details of the allocation that caused a `TryReserveError`.
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum TryReserveErrorKind {
+    /// Error due to the computed capacity exceeding the collection's maximum
+    /// (usually `isize::MAX` bytes).
     CapacityOverflow,
-    AllocError { layout: Layout, non_exhaustive: () },
+
+    /// The memory allocator returned an error.
+    AllocError {
+        /// The layout of allocation request that failed.
+        layout: Layout,
+
+        #[doc(hidden)]
+        non_exhaustive: (),
+    },
 }
 
 impl From<TryReserveErrorKind> for TryReserveError {
@@ -62,4 +63,5 @@ impl Display for TryReserveError {
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for TryReserveError {}
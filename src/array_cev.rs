@@ -0,0 +1,381 @@
+use crate::cev::Cev;
+use crate::front_buf::FrontBuf;
+use core::fmt;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ptr;
+
+/// A `Cev` array with a fixed capacity of `N` elements, stored inline without allocating.
+///
+/// The elements are anchored at the end of the inline array, the same as in a heap `Cev` array,
+/// so [`push`] adds to the beginning without moving the other elements.
+/// Adding to a full `ArrayCev` array returns the element back in a [`CapacityError`].
+///
+/// [`push`]: ArrayCev::push
+///
+/// # Examples
+///
+/// ```
+/// use cev::ArrayCev;
+///
+/// let mut array: ArrayCev<u8, 2> = ArrayCev::new();
+/// array.push(2).unwrap();
+/// array.push(1).unwrap();
+/// assert_eq!(array, [1, 2]);
+///
+/// let err = array.push(0).unwrap_err();
+/// assert_eq!(err.element(), 0);
+/// assert_eq!(array.into_inner(), Ok([1, 2]));
+/// ```
+pub struct ArrayCev<T, const N: usize> {
+    buf: FrontBuf<T, [MaybeUninit<T>; N]>,
+}
+
+impl<T, const N: usize> ArrayCev<T, N> {
+    /// Returns an unsafe mutable pointer to the first element.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.buf.as_mut_ptr()
+    }
+
+    /// Extracts a mutable slice of the entire `ArrayCev` array.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.buf.as_mut_slice()
+    }
+
+    /// Returns an unsafe pointer to the first element.
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        self.buf.as_ptr()
+    }
+
+    /// Extracts a slice of the entire `ArrayCev` array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::ArrayCev;
+    ///
+    /// let array = ArrayCev::from([1, 2, 3]);
+    /// assert_eq!(array.as_slice(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self.buf.as_slice()
+    }
+
+    /// Number of elements the `ArrayCev` array can hold, always `N`.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Clears the `ArrayCev` array, removing all elements.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Converts the `ArrayCev` array into a `Cev` array with a capacity of its length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::{ArrayCev, Cev};
+    ///
+    /// let mut array: ArrayCev<_, 4> = ArrayCev::new();
+    /// array.push(1).unwrap();
+    /// let cev: Cev<_> = array.into_cev();
+    /// assert_eq!(cev, [1]);
+    /// assert_eq!(cev.capacity(), 1);
+    /// ```
+    pub fn into_cev(self) -> Cev<T> {
+        let this = ManuallyDrop::new(self);
+        let len = this.len();
+        let mut cev = Cev::with_capacity(len);
+        unsafe {
+            let dst = cev.spare_capacity_mut().as_mut_ptr() as *mut T;
+            ptr::copy_nonoverlapping(this.as_ptr(), dst, len);
+            cev.set_len_ptr(len);
+        }
+        cev
+    }
+
+    /// Returns the inner array if the `ArrayCev` array is full,
+    /// otherwise returns the `ArrayCev` array back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::ArrayCev;
+    ///
+    /// let mut array: ArrayCev<_, 2> = ArrayCev::new();
+    /// array.push(2).unwrap();
+    /// let mut array = array.into_inner().unwrap_err();
+    /// array.push(1).unwrap();
+    /// assert_eq!(array.into_inner(), Ok([1, 2]));
+    /// ```
+    pub fn into_inner(self) -> Result<[T; N], Self> {
+        if !self.is_full() {
+            return Err(self);
+        }
+
+        let (slots, _) = self.buf.into_parts();
+        Ok(unsafe { ptr::read(slots.as_ptr() as *const [T; N]) })
+    }
+
+    /// Returns `true` if the `ArrayCev` array contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.buf.len() == 0
+    }
+
+    /// Returns `true` if the `ArrayCev` array holds `N` elements.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.buf.len() == N
+    }
+
+    /// `ArrayCev` array length, the number of elements in the array.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Creates a new, empty `ArrayCev<T, N>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::ArrayCev;
+    ///
+    /// const EMPTY: ArrayCev<u32, 16> = ArrayCev::new();
+    /// assert!(EMPTY.is_empty());
+    /// assert_eq!(EMPTY.capacity(), 16);
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        ArrayCev {
+            buf: FrontBuf::new([const { MaybeUninit::uninit() }; N]),
+        }
+    }
+
+    /// Removes the first element from the `ArrayCev` array and returns it,
+    /// or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::ArrayCev;
+    ///
+    /// let mut array = ArrayCev::from([1, 2]);
+    /// assert_eq!(array.pop(), Some(1));
+    /// assert_eq!(array, [2]);
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        self.buf.pop()
+    }
+
+    /// Adds an element to the beginning of the `ArrayCev` array.
+    ///
+    /// # Errors
+    ///
+    /// If the `ArrayCev` array is full, the element is returned in the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::ArrayCev;
+    ///
+    /// let mut array: ArrayCev<_, 1> = ArrayCev::new();
+    /// assert!(array.push(1).is_ok());
+    /// assert_eq!(array.push(2).unwrap_err().element(), 2);
+    /// assert_eq!(array, [1]);
+    /// ```
+    #[inline]
+    pub fn push(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        self.buf.push(value).map_err(CapacityError::new)
+    }
+
+    /// Number of elements that can still be added.
+    #[inline]
+    pub fn remaining_capacity(&self) -> usize {
+        N - self.buf.len()
+    }
+
+    /// Removes and returns the element at position `index` within the `ArrayCev` array,
+    /// shifting all elements before it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::ArrayCev;
+    ///
+    /// let mut array = ArrayCev::from([1, 2, 3]);
+    /// assert_eq!(array.remove(1), 2);
+    /// assert_eq!(array, [1, 3]);
+    /// ```
+    #[track_caller]
+    pub fn remove(&mut self, index: usize) -> T {
+        self.buf.remove(index)
+    }
+
+    /// Reduces the length of the `ArrayCev` array to `len`,
+    /// by removing elements from the beginning of the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::ArrayCev;
+    ///
+    /// let mut array = ArrayCev::from(['T', 'r', 'u', 'n', 'c', 'a', 't', 'e']);
+    /// array.truncate(4);
+    /// assert_eq!(array, ['c', 'a', 't', 'e']);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        self.buf.truncate(len);
+    }
+
+    /// Inserts an element at position `index` within the `ArrayCev` array, shifting all
+    /// elements before it to the left.
+    ///
+    /// # Errors
+    ///
+    /// If the `ArrayCev` array is full, the element is returned in the error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::ArrayCev;
+    ///
+    /// let mut array: ArrayCev<_, 4> = ArrayCev::new();
+    /// array.push(3).unwrap();
+    /// array.push(1).unwrap();
+    /// array.try_insert(1, 2).unwrap();
+    /// array.try_insert(3, 4).unwrap();
+    /// assert_eq!(array, [1, 2, 3, 4]);
+    /// assert!(array.try_insert(0, 0).is_err());
+    /// ```
+    #[track_caller]
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>> {
+        self.buf
+            .try_insert(index, element)
+            .map_err(CapacityError::new)
+    }
+}
+
+impl_slice_traits! { [const N: usize] ArrayCev<T, N> }
+
+impl_slice_eq! { [const N: usize, const M: usize] ArrayCev<T, N>, ArrayCev<U, M> }
+impl_slice_eq! { [const N: usize] ArrayCev<T, N>, [U] }
+impl_slice_eq! { [const N: usize] ArrayCev<T, N>, &[U] }
+impl_slice_eq! { [const N: usize, const M: usize] ArrayCev<T, N>, [U; M] }
+
+impl<T, const N: usize> Default for ArrayCev<T, N> {
+    fn default() -> ArrayCev<T, N> {
+        ArrayCev::new()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayCev<T, N> {
+    fn clone(&self) -> Self {
+        let mut array = ArrayCev::new();
+        for elem in self.iter().rev() {
+            let _ = array.push(elem.clone());
+        }
+        array
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for ArrayCev<T, N> {
+    /// Creates a full `ArrayCev` array from an array.
+    fn from(array: [T; N]) -> ArrayCev<T, N> {
+        let array = ManuallyDrop::new(array);
+        let slots = unsafe { ptr::read(&*array as *const [T; N] as *const [MaybeUninit<T>; N]) };
+        ArrayCev {
+            buf: unsafe { FrontBuf::from_parts(slots, N) },
+        }
+    }
+}
+
+impl<T, const N: usize> From<ArrayCev<T, N>> for Cev<T> {
+    /// Converts an `ArrayCev` array, see [`ArrayCev::into_cev`].
+    fn from(array: ArrayCev<T, N>) -> Cev<T> {
+        array.into_cev()
+    }
+}
+
+impl<T, const N: usize> TryFrom<Cev<T>> for ArrayCev<T, N> {
+    type Error = Cev<T>;
+
+    /// Moves the elements of a `Cev` array into an `ArrayCev` array,
+    /// or returns the `Cev` array back if it has more than `N` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::{ArrayCev, Cev};
+    ///
+    /// let array: ArrayCev<_, 4> = ArrayCev::try_from(Cev::from([1, 2])).unwrap();
+    /// assert_eq!(array, [1, 2]);
+    ///
+    /// let cev = ArrayCev::<_, 1>::try_from(Cev::from([1, 2])).unwrap_err();
+    /// assert_eq!(cev, [1, 2]);
+    /// ```
+    fn try_from(mut cev: Cev<T>) -> Result<ArrayCev<T, N>, Cev<T>> {
+        let len = cev.len();
+        if len > N {
+            return Err(cev);
+        }
+
+        let mut array = ArrayCev::<T, N>::new();
+        unsafe {
+            ptr::copy_nonoverlapping(cev.as_ptr(), array.as_mut_ptr().sub(len), len);
+            cev.set_len(0);
+            array.buf.set_len(len);
+        }
+        Ok(array)
+    }
+}
+
+/// The error type for adding to a full [`ArrayCev`] array,
+/// holding the element that could not be added.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CapacityError<T> {
+    element: T,
+}
+
+impl<T> CapacityError<T> {
+    /// Creates a new `CapacityError` holding `element`.
+    pub const fn new(element: T) -> CapacityError<T> {
+        CapacityError { element }
+    }
+
+    /// Returns the element that could not be added.
+    pub fn element(self) -> T {
+        self.element
+    }
+}
+
+impl<T> fmt::Debug for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CapacityError: insufficient capacity")
+    }
+}
+
+impl<T> fmt::Display for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("insufficient capacity")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for CapacityError<T> {}
@@ -1,15 +1,39 @@
-#![allow(clippy::partialeq_ne_impl)]
+use crate::alloc_err::TryReserveError;
+use crate::cev_slice::CevSlice;
+use crate::growth::{Doubling, GrowthPolicy};
 use crate::raw_cev::RawCev;
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::rc::Rc;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use allocator_api2::alloc::{Allocator, Global};
 use core::borrow::{Borrow, BorrowMut};
-use core::cmp::Ordering;
+use core::cmp::{self, Ordering};
 use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter;
 use core::mem::{self, ManuallyDrop, MaybeUninit};
-use core::ops::{self, Index, IndexMut};
+use core::ops::{self, Bound, Index, IndexMut, RangeBounds};
 use core::ptr::{self, NonNull};
 use core::slice::{self, SliceIndex};
 
+mod drain;
+mod extract_if;
+#[cfg(feature = "std")]
+mod io;
+mod splice;
+
+pub use self::drain::Drain;
+pub use self::extract_if::ExtractIf;
+pub use self::splice::Splice;
+
 /// An array of data allocated on the heap that grows from end to beginning.
 ///
+/// The memory is obtained from the allocator `A`, which is [`Global`] by default.
+///
 /// # Examples
 ///
 /// ```
@@ -23,157 +47,80 @@ use core::slice::{self, SliceIndex};
 /// assert_eq!(cev, ['c', 'e', 'v']);
 ///
 /// ```
-pub struct Cev<T> {
-    buf: RawCev<T>,
+pub struct Cev<T, A: Allocator = Global, G: GrowthPolicy = Doubling> {
+    buf: RawCev<T, A, G>,
     len: usize,
 }
 
 impl<T> Cev<T> {
-    /// Adds elements to the beginning of the `Cev` array, moving them from another `Cev` array,
-    /// leaving the other empty to reuse the allocated memory.
+    /// Creates a `Cev<T>` from the items of the iterator in reversed order.
     ///
-    /// # Examples
+    /// Every item is written once, directly into its final position in front of the
+    /// previous one. The lower bound of `size_hint` is allocated up front and the
+    /// allocation grows as needed beyond it.
+    /// Collecting `iter.rev()` keeps the order of a double-ended iterator
+    /// in a single pass, whatever its `size_hint`.
     ///
-    /// ```
-    /// use cev::Cev;
+    /// # Panics
     ///
-    /// let mut cev = Cev::from([4, 5, 6]);
-    /// let mut cev_other = Cev::from([1, 2, 3]);
-    /// cev.append(&mut cev_other);
-    /// assert_eq!(cev, [1, 2, 3, 4, 5, 6]);
-    /// assert_eq!(cev_other, []);
-    /// ```
-    #[inline]
-    pub fn append(&mut self, other: &mut Self) {
-        unsafe {
-            self.append_elements(other.as_slice() as _);
-            other.set_len_ptr(0);
-        }
-    }
-
-    /// Returns an unsafe mutable pointer.
-    /// If length is zero, then points to capacity minus 1 element of type `T`,
-    /// otherwise capacity minus length.
-    /// For unallocated array `Cev` and types of size zero, a `NonNull` dangling pointer is returned.
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
     ///
     /// # Examples
     ///
     /// ```
-    /// use::cev::Cev;
-    ///
-    /// let len = 5;
-    /// let mut cev = Cev::<u8>::with_capacity(len);
-    /// let mov_ptr = cev.as_mut_ptr();
+    /// use cev::Cev;
     ///
-    /// unsafe {
-    ///     for elem in 0..len {
-    ///         *mov_ptr.sub(elem) = elem as u8;
-    ///     }
-    ///     cev.set_len_ptr(len);
-    /// }
+    /// let cev = Cev::from_iter_rev([1, 2, 3]);
+    /// assert_eq!(cev, [3, 2, 1]);
     ///
-    /// // The `mov_ptr` pointer points to the element at index 4.
-    /// assert_eq!(unsafe { *mov_ptr }, 0);
-    /// assert_eq!(cev, [4, 3, 2, 1, 0]);
+    /// let cev = Cev::from_iter_rev((0..10).filter(|x| x % 3 == 0).rev());
+    /// assert_eq!(cev, [0, 3, 6, 9]);
     /// ```
-    #[inline]
-    pub fn as_mut_ptr(&mut self) -> *mut T {
-        self.buf.ptr()
-    }
-
-    /// A mutable slice from this `Cev` array.
-    #[inline]
-    pub fn as_mut_slice(&mut self) -> &mut [T] {
-        self
+    pub fn from_iter_rev<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut cev = Cev::with_capacity(iter.size_hint().0);
+        cev.extend_front_chunked(iter);
+        cev
     }
 
-    /// Returns an unsafe constant pointer.
-    /// If length is zero, then points to capacity minus 1 element of type `T`,
-    /// otherwise capacity minus length.
-    /// For unallocated array `Cev` and types of size zero, a `NonNull` dangling pointer is returned.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use::cev::Cev;
-    ///
-    /// let mut cev = Cev::<u8>::from_vec(vec![2, 4, 6, 8, 10]);
-    /// let len = cev.len();
-    /// let mov_ptr = cev.as_mut_ptr();
+    /// Creates a `Cev<T>` directly from a pointers, a capacity, and a length.
     ///
-    /// unsafe {
-    ///     for index in 0..len {
-    ///         
-    ///         assert_eq!(unsafe { *mov_ptr.add(index) }, cev[index]);
-    ///     }
-    /// }
+    /// # Safety
+    /// - `raw_ptr` must be allocated with the global allocator for `cap` elements of type `T`.
+    /// - `mov_ptr` must point to the first of the `len` initialized elements at the end
+    ///   of the allocation, that is `raw_ptr.add(cap - len)`.
     ///
-    /// ```
-    #[inline]
-    pub fn as_ptr(&self) -> *const T {
-        self.buf.ptr()
-    }
-
-    /// A constant slice from this `Cev` array.
+    /// Only `raw_ptr` is stored, the position of the first element is recomputed
+    /// from the capacity and the length.
     #[inline]
-    pub fn as_slice(&self) -> &[T] {
-        self
-    }
+    pub unsafe fn from_raw_parts(mov_ptr: *mut T, raw_ptr: *mut T, len: usize, cap: usize) -> Self {
+        debug_assert_eq!(mov_ptr, raw_ptr.wrapping_add(cap - len));
 
-    /// Returns the capacity of this `Cev` array.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use cev::Cev;
-    ///
-    /// let cev = Cev::<String>::with_capacity(0);
-    /// assert_eq!(cev.capacity(), 0);
-    ///
-    /// let cev = Cev::<String>::with_capacity(1);
-    /// assert_eq!(cev.capacity(), 1);
-    ///
-    /// let cev = Cev::<()>::with_capacity(0);
-    /// assert_eq!(cev.capacity(), usize::MAX);
-    /// ```
-    #[inline]
-    pub fn capacity(&self) -> usize {
-        self.buf.capacity()
+        unsafe {
+            Cev {
+                buf: RawCev::from_raw_parts_ptr(raw_ptr, cap),
+                len,
+            }
+        }
     }
 
-    /// Clears the `Cev` array, removing all elements.
+    /// Creates a `CevSlice` array over caller-provided storage,
+    /// see [`CevSlice`] for the details.
     ///
     /// # Examples
     ///
     /// ```
     /// use cev::Cev;
+    /// use std::mem::MaybeUninit;
     ///
-    /// let mut cev: Cev<&str> = Cev::from(["c", "l", "e", "a", "r"]);
-    /// cev.clear();
-    /// assert!(cev.is_empty());
-    ///
-    /// cev.append(&mut Cev::from_vec(vec!["r", "e", "u", "s", "e"]));
-    /// assert_eq!(cev, Cev::from_vec(vec!["r", "e", "u", "s", "e"]));
+    /// let mut storage = [MaybeUninit::uninit(); 2];
+    /// let mut cev = Cev::from_uninit_slice(&mut storage);
+    /// cev.push(1).unwrap();
+    /// assert_eq!(cev, [1]);
     /// ```
     #[inline]
-    pub fn clear(&mut self) {
-        let elems: *mut [T] = self.as_mut_slice();
-
-        unsafe {
-            self.set_len_ptr(0);
-            ptr::drop_in_place(elems);
-        }
-    }
-
-    /// Creates a `Cev<T>` directly from a pointers, a capacity, and a length.
-    #[inline]
-    pub unsafe fn from_raw_parts(mov_ptr: *mut T, raw_ptr: *mut T, len: usize, cap: usize) -> Self {
-        unsafe {
-            Cev {
-                buf: RawCev::from_raw_parts_ptr(mov_ptr, raw_ptr, cap),
-                len,
-            }
-        }
+    pub fn from_uninit_slice(buf: &mut [MaybeUninit<T>]) -> CevSlice<'_, T> {
+        CevSlice::new(buf)
     }
 
     /// Converting a std vector to a `Cev` array.
@@ -200,8 +147,6 @@ impl<T> Cev<T> {
             Cev::from_raw_parts(
                 if capacity == len {
                     raw_ptr
-                } else if len == 0 {
-                    raw_ptr.add(capacity - 1)
                 } else {
                     let mov_ptr = raw_ptr.add(capacity - len);
                     ptr::copy(raw_ptr, mov_ptr, len);
@@ -214,53 +159,31 @@ impl<T> Cev<T> {
         }
     }
 
-    /// Inserts an element at position `index` within the `Cev` array, shifting all
-    /// elements before it to the left.
+    /// Converts the `Cev` array into `Box<[T]>`.
     ///
-    /// # Panics
+    /// When length and capacity are equal, the allocation is reused without copying.
+    /// Otherwise the excess capacity is dropped first, see [`shrink_to_fit`].
     ///
-    /// Panics if `index > len`.
+    /// [`shrink_to_fit`]: Cev::shrink_to_fit
     ///
     /// # Examples
     ///
     /// ```
     /// use cev::Cev;
     ///
-    /// let mut cev = Cev::from([2, 2, 3]);
-    /// cev.insert(1, 0);
-    /// assert_eq!(cev, [2, 0, 2, 3]);
-    /// cev.insert(4, 8);
-    /// assert_eq!(cev, [2, 0, 2, 3, 8]);
+    /// let cev = Cev::from([1, 2, 3]);
+    /// let slice = cev.into_boxed_slice();
+    /// assert_eq!(&*slice, [1, 2, 3]);
+    ///
+    /// let mut cev = Cev::with_capacity(10);
+    /// cev.push(1);
+    /// assert_eq!(cev.capacity(), 10);
+    /// let slice = cev.into_boxed_slice();
+    /// assert_eq!(slice.into_vec().capacity(), 1);
     /// ```
-    pub fn insert(&mut self, index: usize, element: T) {
-        #[cold]
-        #[inline(never)]
-        fn assert_failed(index: usize, len: usize) -> ! {
-            panic!("insertion index (is {index}) should be <= len (is {len})");
-        }
-
-        let len = self.len();
-
-        if len == self.buf.capacity() {
-            self.reserve(1);
-        }
-
-        unsafe {
-            if index == 0 {
-                if len != 0 {
-                    self.buf.mov_ptr_sub(1)
-                }
-            } else if index <= len {
-                let p = self.as_mut_ptr();
-                ptr::copy(p, p.sub(1), index);
-                self.buf.mov_ptr_sub(1)
-            } else {
-                assert_failed(index, len);
-            }
-
-            ptr::write(self.as_mut_ptr().add(index), element);
-            self.set_len(len + 1);
-        }
+    pub fn into_boxed_slice(mut self) -> Box<[T]> {
+        self.shrink_to_fit();
+        self.into_vec().into_boxed_slice()
     }
 
     /// Converting a `Cev` array to a `std` vector.
@@ -290,332 +213,1932 @@ impl<T> Cev<T> {
         }
     }
 
-    /// Returns `true` if the `Cev` array contains no elements.
+    /// Creates a new, empty `Cev<T>`.
     ///
     /// # Examples
     ///
     /// ```
     /// use cev::Cev;
     ///
-    /// let mut cev = Cev::new();
-    /// assert!(cev.is_empty());
-    ///
-    /// cev.push("push one element");
-    /// assert!(!cev.is_empty());
+    /// let cev: Cev<i64> = Cev::new();
     /// ```
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    #[inline]
+    pub const fn new() -> Self {
+        Cev {
+            buf: RawCev::NEW,
+            len: 0,
+        }
     }
 
-    /// `Cev` array length, the number of elements in the array.
+    /// Creates a new `Cev` array with the given capacity,
+    /// returning an error instead of panicking if the memory cannot be allocated.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity exceeds `isize::MAX` bytes, or the allocator reports a failure,
+    /// then an error is returned.
     ///
     /// # Examples
     ///
     /// ```
     /// use cev::Cev;
     ///
-    /// let cev = Cev::from([1, 2, 3]);
-    /// assert_eq!(cev.len(), 3);
+    /// let cev = Cev::<u32>::try_with_capacity(5).unwrap();
+    /// assert_eq!(cev.capacity(), 5);
+    ///
+    /// assert!(Cev::<u32>::try_with_capacity(usize::MAX).is_err());
     /// ```
     #[inline]
-    pub fn len(&self) -> usize {
-        self.len
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        Ok(Cev {
+            buf: RawCev::try_with_capacity(capacity)?,
+            len: 0,
+        })
     }
 
-    /// Creates a new, empty `Cev<T>`.
+    /// Creates a new `Cev` array and allocates memory for type `T` with the given capacity.
+    /// If `capacity` is null, no memory is allocated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
     ///
     /// # Examples
     ///
     /// ```
     /// use cev::Cev;
+    /// use std::ptr::NonNull;
+    ///
+    /// let mut cev = Cev::with_capacity(0);
+    /// assert_eq!(cev.as_ptr(), NonNull::<u32>::dangling().as_ptr());
+    ///
+    /// let capacity = 5;
+    /// let mut cev = Cev::with_capacity(capacity);
+    ///
+    /// assert!(cev.capacity() >= capacity);
+    ///
+    /// for val in 0..=capacity {
+    ///     cev.insert(0, val);
+    /// }
+    /// assert_eq!(cev, [5, 4, 3, 2, 1, 0]);
     ///
-    /// let cev: Cev<i64> = Cev::new();
     /// ```
     #[inline]
-    pub const fn new() -> Self {
+    pub fn with_capacity(capacity: usize) -> Self {
         Cev {
-            buf: RawCev::NEW,
+            buf: RawCev::with_capacity(capacity),
             len: 0,
         }
     }
+}
 
-    /// Removes the first element from a collection and returns it, or None if it is empty.
+impl<T, A: Allocator> Cev<T, A> {
+    /// Creates a `Cev<T, A>` directly from a pointers, a capacity, a length and an allocator.
+    ///
+    /// # Safety
+    /// - `raw_ptr` must be allocated with `alloc` for `cap` elements of type `T`.
+    /// - `mov_ptr` must point to the first of the `len` initialized elements at the end
+    ///   of the allocation, that is `raw_ptr.add(cap - len)`.
+    ///
+    /// Only `raw_ptr` is stored, the position of the first element is recomputed
+    /// from the capacity and the length.
+    #[inline]
+    pub unsafe fn from_raw_parts_in(
+        mov_ptr: *mut T,
+        raw_ptr: *mut T,
+        len: usize,
+        cap: usize,
+        alloc: A,
+    ) -> Self {
+        debug_assert_eq!(mov_ptr, raw_ptr.wrapping_add(cap - len));
+
+        unsafe {
+            Cev {
+                buf: RawCev::from_raw_parts_ptr_in(raw_ptr, cap, alloc),
+                len,
+            }
+        }
+    }
+
+    /// Creates a new, empty `Cev<T, A>` in the provided allocator.
+    /// No memory is allocated until elements are pushed.
     ///
     /// # Examples
     ///
     /// ```
-    /// use cev::Cev;
+    /// use cev::{Cev, Global};
     ///
-    /// let mut cev = Cev::from([1, 9, 7, 5]);
-    /// assert_eq!(cev.pop(), Some(1));
-    /// assert_eq!(cev, [9, 7, 5]);
-    /// assert_eq!(cev.pop(), Some(9));
-    /// assert_eq!(cev.pop(), Some(7));
-    /// assert_eq!(cev.pop(), Some(5));
-    /// assert_eq!(cev.pop(), None);
+    /// let mut cev: Cev<i64> = Cev::new_in(Global);
+    /// cev.push(1);
+    /// assert_eq!(cev, [1]);
     /// ```
     #[inline]
-    pub fn pop(&mut self) -> Option<T> {
-        if self.len == 0 {
-            None
-        } else {
-            unsafe {
-                self.len -= 1;
-                let ptr = self.as_ptr();
-                if self.len != 0 {
-                    self.buf.mov_ptr_add(1);
-                }
-                Some(ptr::read(ptr))
-            }
+    pub const fn new_in(alloc: A) -> Self {
+        Cev {
+            buf: RawCev::new_in(alloc),
+            len: 0,
         }
     }
 
-    /// Appends an element to the beginning of a collection.
+    /// Creates a new `Cev` array with the given capacity in the provided allocator,
+    /// returning an error instead of panicking if the memory cannot be allocated.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    /// If the capacity exceeds `isize::MAX` bytes, or the allocator reports a failure,
+    /// then an error is returned.
     ///
     /// # Examples
     ///
     /// ```
-    /// use cev::Cev;
+    /// use cev::{Cev, Global};
     ///
-    /// let mut cev: Cev<i8> = Cev::new();
-    /// cev.push(1);
-    /// cev.push(2);
-    /// cev.push(3);
-    /// assert_eq!(cev, [3, 2, 1]);
+    /// let cev = Cev::<u32>::try_with_capacity_in(5, Global).unwrap();
+    /// assert_eq!(cev.capacity(), 5);
+    ///
+    /// assert!(Cev::<u32>::try_with_capacity_in(usize::MAX, Global).is_err());
     /// ```
     #[inline]
-    pub fn push(&mut self, value: T) {
-        if self.len == self.capacity() {
-            self.buf.reserve_for_push(self.len);
-        }
-        unsafe {
-            if self.len != 0 {
-                self.buf.mov_ptr_sub(1);
-            }
-            self.as_mut_ptr().write(value);
+    pub fn try_with_capacity_in(capacity: usize, alloc: A) -> Result<Self, TryReserveError> {
+        Ok(Cev {
+            buf: RawCev::try_with_capacity_in(capacity, alloc)?,
+            len: 0,
+        })
+    }
+
+    /// Creates a new `Cev` array with the given capacity in the provided allocator.
+    /// If `capacity` is null, no memory is allocated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::{Cev, Global};
+    ///
+    /// let mut cev = Cev::with_capacity_in(5, Global);
+    /// assert_eq!(cev.capacity(), 5);
+    ///
+    /// cev.push(1);
+    /// assert_eq!(cev, [1]);
+    /// ```
+    #[inline]
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        Cev {
+            buf: RawCev::with_capacity_in(capacity, alloc),
+            len: 0,
+        }
+    }
+}
+
+impl<T, A: Allocator, G: GrowthPolicy> Cev<T, A, G> {
+    /// Returns a reference to the underlying allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::{Cev, Global};
+    ///
+    /// let cev: Cev<u8> = Cev::new_in(Global);
+    /// let _alloc: &Global = cev.allocator();
+    /// ```
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        self.buf.allocator()
+    }
+
+    /// Adds elements to the beginning of the `Cev` array, moving them from another `Cev` array,
+    /// leaving the other empty to reuse the allocated memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from([4, 5, 6]);
+    /// let mut cev_other = Cev::from([1, 2, 3]);
+    /// cev.append(&mut cev_other);
+    /// assert_eq!(cev, [1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(cev_other, []);
+    /// ```
+    #[inline]
+    pub fn append(&mut self, other: &mut Self) {
+        unsafe {
+            self.append_elements(other.as_slice() as _);
+            other.set_len_ptr(0);
+        }
+    }
+
+    /// Returns an unsafe mutable pointer.
+    /// Points to the first element, at capacity minus length elements of type `T`
+    /// from the start of the allocation, or one past the end of the allocation if length is zero.
+    /// For unallocated array `Cev` and types of size zero, a `NonNull` dangling pointer is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use::cev::Cev;
+    ///
+    /// let len = 5;
+    /// let mut cev = Cev::<u8>::with_capacity(len);
+    /// let mov_ptr = cev.as_mut_ptr();
+    ///
+    /// unsafe {
+    ///     for elem in 0..len {
+    ///         *mov_ptr.sub(elem + 1) = elem as u8;
+    ///     }
+    ///     cev.set_len_ptr(len);
+    /// }
+    ///
+    /// // The empty array pointed one past the end, now the pointer is at index 0.
+    /// assert_eq!(mov_ptr, unsafe { cev.as_mut_ptr().add(len) });
+    /// assert_eq!(cev, [4, 3, 2, 1, 0]);
+    /// ```
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        unsafe { self.mov_ptr(self.len) }
+    }
+
+    /// A mutable slice from this `Cev` array.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+
+    /// Returns an unsafe constant pointer.
+    /// Points to the first element, at capacity minus length elements of type `T`
+    /// from the start of the allocation, or one past the end of the allocation if length is zero.
+    /// For unallocated array `Cev` and types of size zero, a `NonNull` dangling pointer is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use::cev::Cev;
+    ///
+    /// let mut cev = Cev::<u8>::from_vec(vec![2, 4, 6, 8, 10]);
+    /// let len = cev.len();
+    /// let mov_ptr = cev.as_mut_ptr();
+    ///
+    /// unsafe {
+    ///     for index in 0..len {
+    ///         
+    ///         assert_eq!(unsafe { *mov_ptr.add(index) }, cev[index]);
+    ///     }
+    /// }
+    ///
+    /// ```
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        unsafe { self.mov_ptr(self.len) }
+    }
+
+    /// A constant slice from this `Cev` array.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    /// Returns the capacity of this `Cev` array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let cev = Cev::<String>::with_capacity(0);
+    /// assert_eq!(cev.capacity(), 0);
+    ///
+    /// let cev = Cev::<String>::with_capacity(1);
+    /// assert_eq!(cev.capacity(), 1);
+    ///
+    /// let cev = Cev::<()>::with_capacity(0);
+    /// assert_eq!(cev.capacity(), usize::MAX);
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buf.capacity()
+    }
+
+    /// Clears the `Cev` array, removing all elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev: Cev<&str> = Cev::from(["c", "l", "e", "a", "r"]);
+    /// cev.clear();
+    /// assert!(cev.is_empty());
+    ///
+    /// cev.append(&mut Cev::from_vec(vec!["r", "e", "u", "s", "e"]));
+    /// assert_eq!(cev, Cev::from_vec(vec!["r", "e", "u", "s", "e"]));
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        let elems: *mut [T] = self.as_mut_slice();
+
+        unsafe {
+            self.set_len_ptr(0);
+            ptr::drop_in_place(elems);
+        }
+    }
+
+    /// Removes all but the first of consecutive elements in the `Cev` array satisfying
+    /// a given equality relation.
+    ///
+    /// The `same_bucket` function is passed references to two elements from the array and
+    /// must determine if the elements compare equal. The elements are passed in opposite order
+    /// from their order in the array, so if `same_bucket(a, b)` returns `true`, `a` is removed.
+    /// The retained elements are slid towards the end of the array once, after all are visited.
+    ///
+    /// If the array is sorted, this removes all duplicates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from(["foo", "bar", "Bar", "baz", "bar"]);
+    /// cev.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(cev, ["foo", "bar", "baz", "bar"]);
+    /// ```
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let len = self.len();
+        if len <= 1 {
+            return;
+        }
+
+        // Check if we ever want to remove anything.
+        // This allows to use copy_nonoverlapping in the next cycle
+        // and avoids any memory writes if we don't need to remove anything.
+        let base = self.as_mut_ptr();
+        let mut first_duplicate_idx = 1;
+        while first_duplicate_idx != len {
+            let found_duplicate = unsafe {
+                let prev = base.add(first_duplicate_idx - 1);
+                let current = base.add(first_duplicate_idx);
+                same_bucket(&mut *current, &mut *prev)
+            };
+            if found_duplicate {
+                break;
+            }
+            first_duplicate_idx += 1;
+        }
+        if first_duplicate_idx == len {
+            return;
+        }
+
+        /* INVARIANT: [0, write) are retained, [write, read) are dropped, [read, len) are unprocessed */
+        struct FillGapOnDrop<'a, T, A: Allocator, G: GrowthPolicy> {
+            read: usize,
+            write: usize,
+            len: usize,
+            base: *mut T,
+            cev: &'a mut Cev<T, A, G>,
+        }
+
+        impl<'a, T, A: Allocator, G: GrowthPolicy> Drop for FillGapOnDrop<'a, T, A, G> {
+            fn drop(&mut self) {
+                let removed = self.read - self.write;
+                unsafe {
+                    // Slide the retained elements to the unprocessed ones at the end.
+                    ptr::copy(self.base, self.base.add(removed), self.write);
+                    self.cev.set_len_ptr(self.len - removed);
+                }
+            }
+        }
+
+        let mut gap = FillGapOnDrop {
+            read: first_duplicate_idx + 1,
+            write: first_duplicate_idx,
+            len,
+            base,
+            cev: self,
+        };
+
+        unsafe {
+            // The guard is already in place, so a panic in the drop still closes the gap.
+            ptr::drop_in_place(base.add(first_duplicate_idx));
+
+            while gap.read < len {
+                let read_ptr = base.add(gap.read);
+                let prev_ptr = base.add(gap.write - 1);
+
+                if same_bucket(&mut *read_ptr, &mut *prev_ptr) {
+                    // Increase `gap.read` now since the drop may panic.
+                    gap.read += 1;
+                    ptr::drop_in_place(read_ptr);
+                } else {
+                    ptr::copy_nonoverlapping(read_ptr, base.add(gap.write), 1);
+                    gap.write += 1;
+                    gap.read += 1;
+                }
+            }
+        }
+    }
+
+    /// Removes all but the first of consecutive elements in the `Cev` array
+    /// that resolve to the same key.
+    ///
+    /// If the array is sorted, this removes all duplicates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from([10, 20, 21, 30, 20]);
+    /// cev.dedup_by_key(|i| *i / 10);
+    /// assert_eq!(cev, [10, 20, 30, 20]);
+    /// ```
+    #[inline]
+    pub fn dedup_by_key<F, K>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b))
+    }
+
+    /// Removes the specified range from the `Cev` array in bulk, returning all
+    /// removed elements as an iterator. If the iterator is dropped before
+    /// being fully consumed, it drops the remaining removed elements.
+    ///
+    /// The elements before the range are slid towards the end of the array to close the gap,
+    /// so draining near the beginning of the array is cheap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if
+    /// the end point is greater than the length of the `Cev` array.
+    ///
+    /// # Leaking
+    ///
+    /// If the returned iterator goes out of scope without being dropped (due to
+    /// [`mem::forget`], for example), the `Cev` array keeps only the elements after the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from([1, 2, 3, 4, 5]);
+    /// let drained: Vec<_> = cev.drain(1..3).collect();
+    /// assert_eq!(cev, [1, 4, 5]);
+    /// assert_eq!(drained, [2, 3]);
+    ///
+    /// // A full range clears the array, like `clear()` does
+    /// cev.drain(..);
+    /// assert_eq!(cev, []);
+    /// ```
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T, A, G>
+    where
+        R: RangeBounds<usize>,
+    {
+        let len = self.len();
+        let ops::Range { start, end } = slice_range(range, len);
+
+        unsafe {
+            let head_start = self.capacity() - len;
+            let range_slice = slice::from_raw_parts(self.as_ptr().add(start), end - start);
+            self.set_len_ptr(len - end);
+
+            Drain {
+                head_start,
+                head_len: start,
+                iter: range_slice.iter(),
+                cev: NonNull::from(self),
+            }
+        }
+    }
+
+    /// Creates an iterator which uses a closure to determine if an element in the range
+    /// should be removed.
+    ///
+    /// If the closure returns `true`, the element is removed from the `Cev` array
+    /// and yielded. If the closure returns `false`, or panics, the element
+    /// remains in the array and will not be yielded.
+    ///
+    /// The elements of the range are visited and yielded in their original order,
+    /// and the retained ones keep their relative order, like with [`retain_mut`].
+    /// If the returned `ExtractIf` is not exhausted, e.g. because it is dropped without iterating
+    /// or the iteration short-circuits, then the remaining elements will be retained.
+    ///
+    /// [`retain_mut`]: Cev::retain_mut
+    ///
+    /// # Panics
+    ///
+    /// If `range` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut numbers = Cev::from([1, 2, 3, 4, 5, 6, 8, 9, 11, 13, 14, 15]);
+    ///
+    /// let evens = numbers.extract_if(.., |x| *x % 2 == 0).collect::<Vec<_>>();
+    /// let odds = numbers;
+    ///
+    /// assert_eq!(evens, [2, 4, 6, 8, 14]);
+    /// assert_eq!(odds, [1, 3, 5, 9, 11, 13, 15]);
+    /// ```
+    ///
+    /// Using the range argument to only process a part of the array:
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut items = Cev::from([0, 0, 0, 0, 0, 0, 0, 1, 2, 1, 2, 1, 2]);
+    /// let ones = items.extract_if(7.., |x| *x == 1).collect::<Vec<_>>();
+    /// assert_eq!(items, [0, 0, 0, 0, 0, 0, 0, 2, 2, 2]);
+    /// assert_eq!(ones.len(), 3);
+    /// ```
+    pub fn extract_if<F, R>(&mut self, range: R, filter: F) -> ExtractIf<'_, T, F, A, G>
+    where
+        F: FnMut(&mut T) -> bool,
+        R: RangeBounds<usize>,
+    {
+        let old_len = self.len();
+        let ops::Range { start, end } = slice_range(range, old_len);
+        let base = self.as_mut_ptr();
+
+        // Guard against the `Cev` array getting leaked (leak amplification):
+        // only the elements after the range are kept if `ExtractIf` is forgotten.
+        unsafe { self.set_len_ptr(old_len - end) };
+
+        ExtractIf {
+            cev: self,
+            base,
+            idx: start,
+            end,
+            del: 0,
+            old_len,
+            pred: filter,
+        }
+    }
+
+    /// Adds all elements of the iterator to the beginning of the `Cev` array,
+    /// keeping them in the order they were yielded.
+    ///
+    /// Unlike [`extend`], which pushes every item to the front and therefore
+    /// reverses the sequence, the whole sequence is placed in front of the existing elements.
+    ///
+    /// If the iterator reports an exact `size_hint`, the capacity is reserved once
+    /// and every item is written directly into its final position.
+    /// Otherwise the items are pushed to the front in chunks that fill the spare capacity,
+    /// growing the allocation between chunks, and the added elements are reversed in place at the end.
+    ///
+    /// [`extend`]: Extend::extend
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from([4, 5]);
+    /// cev.extend_front_ordered(1..=3);
+    /// assert_eq!(cev, [1, 2, 3, 4, 5]);
+    ///
+    /// cev.extend(6..=7);
+    /// assert_eq!(cev, [7, 6, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn extend_front_ordered<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        struct ReverseOnDrop<'a, T, A: Allocator, G: GrowthPolicy> {
+            cev: &'a mut Cev<T, A, G>,
+            old_len: usize,
+        }
+
+        impl<T, A: Allocator, G: GrowthPolicy> Drop for ReverseOnDrop<'_, T, A, G> {
+            fn drop(&mut self) {
+                let added = self.cev.len() - self.old_len;
+                self.cev[..added].reverse();
+            }
+        }
+
+        let mut iter = iter.into_iter();
+        if let (lower, Some(upper)) = iter.size_hint() {
+            if lower == upper {
+                self.extend_front_exact(&mut iter, lower);
+
+                // Only taken if the iterator yields more items than it reported.
+                if let Some(elem) = iter.next() {
+                    self.insert_remaining(lower, elem, iter);
+                }
+                return;
+            }
+        }
+
+        let old_len = self.len();
+        let guard = ReverseOnDrop { cev: self, old_len };
+        guard.cev.extend_front_chunked(iter);
+    }
+
+    /// Returns a reference to the growth policy of the `Cev` array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::{Cev, FixedIncrement};
+    ///
+    /// let cev: Cev<u8, _, _> = Cev::new().with_growth_policy(FixedIncrement(64));
+    /// assert_eq!(cev.growth_policy(), &FixedIncrement(64));
+    /// ```
+    #[inline]
+    pub fn growth_policy(&self) -> &G {
+        self.buf.growth_policy()
+    }
+
+    /// Returns a mutable reference to the growth policy of the `Cev` array,
+    /// to adjust it at run time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::{Cev, FixedIncrement};
+    ///
+    /// let mut cev = Cev::new().with_growth_policy(FixedIncrement(64));
+    /// cev.growth_policy_mut().0 = 8;
+    /// cev.push(1u64);
+    /// assert_eq!(cev.capacity(), 8);
+    /// ```
+    #[inline]
+    pub fn growth_policy_mut(&mut self) -> &mut G {
+        self.buf.growth_policy_mut()
+    }
+
+    /// Inserts an element at position `index` within the `Cev` array, shifting all
+    /// elements before it to the left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from([2, 2, 3]);
+    /// cev.insert(1, 0);
+    /// assert_eq!(cev, [2, 0, 2, 3]);
+    /// cev.insert(4, 8);
+    /// assert_eq!(cev, [2, 0, 2, 3, 8]);
+    /// ```
+    pub fn insert(&mut self, index: usize, element: T) {
+        let len = self.len();
+
+        if index > len {
+            insert_assert_failed(index, len);
+        }
+
+        if len == self.buf.capacity() {
+            self.reserve(1);
+        }
+
+        unsafe { self.insert_unchecked(index, element) };
+    }
+
+    /// Returns `true` if the `Cev` array contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::new();
+    /// assert!(cev.is_empty());
+    ///
+    /// cev.push("push one element");
+    /// assert!(!cev.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// `Cev` array length, the number of elements in the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let cev = Cev::from([1, 2, 3]);
+    /// assert_eq!(cev.len(), 3);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Removes the first element from a collection and returns it, or None if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from([1, 9, 7, 5]);
+    /// assert_eq!(cev.pop(), Some(1));
+    /// assert_eq!(cev, [9, 7, 5]);
+    /// assert_eq!(cev.pop(), Some(9));
+    /// assert_eq!(cev.pop(), Some(7));
+    /// assert_eq!(cev.pop(), Some(5));
+    /// assert_eq!(cev.pop(), None);
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            unsafe {
+                let ptr = self.as_ptr();
+                self.len -= 1;
+                Some(ptr::read(ptr))
+            }
+        }
+    }
+
+    /// Appends an element to the beginning of a collection.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev: Cev<i8> = Cev::new();
+    /// cev.push(1);
+    /// cev.push(2);
+    /// cev.push(3);
+    /// assert_eq!(cev, [3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        if self.len == self.capacity() {
+            self.buf.reserve_for_push(self.len);
+        }
+        unsafe {
+            self.as_mut_ptr().sub(1).write(value);
+            self.len += 1;
+        };
+    }
+
+    /// Returns a constant unsafe pointer.
+    /// Pointer for alloc and dealloc memory.
+    #[inline]
+    pub fn raw_ptr(&self) -> *const T {
+        self.buf.raw_ptr()
+    }
+
+    /// Removes and returns the element at position `index` within the `Cev` array,
+    /// shifting all elements before it to the right.
+    ///
+    /// Only the elements before `index` are moved, so removing near the beginning
+    /// of the array is cheap. If you don't need the order of elements to be preserved,
+    /// use [`swap_remove`] instead.
+    ///
+    /// [`swap_remove`]: Cev::swap_remove
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from([1, 2, 3]);
+    /// assert_eq!(cev.remove(1), 2);
+    /// assert_eq!(cev, [1, 3]);
+    /// ```
+    #[track_caller]
+    pub fn remove(&mut self, index: usize) -> T {
+        #[cold]
+        #[inline(never)]
+        #[track_caller]
+        fn assert_failed(index: usize, len: usize) -> ! {
+            panic!("removal index (is {index}) should be < len (is {len})");
+        }
+
+        let len = self.len();
+        if index >= len {
+            assert_failed(index, len);
+        }
+
+        unsafe {
+            let ptr = self.as_mut_ptr();
+            let ret = ptr::read(ptr.add(index));
+            ptr::copy(ptr, ptr.add(1), index);
+            self.set_len_ptr(len - 1);
+            ret
+        }
+    }
+
+    /// Reserves capacity for the elements in this collection.
+    /// It is possible that more space will be reserved than specified.
+    /// Does nothing if capacity is already sufficient.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from(["one"]);
+    /// cev.reserve(5);
+    /// assert!(cev.capacity() >= 6);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.buf.reserve(self.len, additional);
+    }
+
+    /// Reserves the minimum capacity for exactly `additional` more elements,
+    /// bypassing the growth policy.
+    /// Does nothing if the capacity is already sufficient.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from([1]);
+    /// cev.reserve_exact(10);
+    /// assert_eq!(cev.capacity(), 11);
+    /// assert_eq!(cev, [1]);
+    /// ```
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.buf.reserve_exact(self.len, additional);
+    }
+
+    /// Resizes the `Cev` array in-place so that `len` is equal to `new_len`,
+    /// using [`Default`] to create the new elements.
+    ///
+    /// If `new_len` is greater than `len`, the `Cev` array is extended at the beginning
+    /// by the difference, with each additional slot filled with `T::default()`.
+    /// If `new_len` is less than `len`, the array is simply truncated from the beginning.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from([1, 2, 3]);
+    /// cev.resize_default(5);
+    /// assert_eq!(cev, [0, 0, 1, 2, 3]);
+    ///
+    /// cev.resize_default(2);
+    /// assert_eq!(cev, [2, 3]);
+    /// ```
+    pub fn resize_default(&mut self, new_len: usize)
+    where
+        T: Default,
+    {
+        self.resize_with(new_len, T::default);
+    }
+
+    /// Resizes the `Cev` array in-place so that `len` is equal to `new_len`.
+    ///
+    /// If `new_len` is greater than `len`, the `Cev` array is extended at the beginning
+    /// by the difference, with each additional slot filled with the result of calling
+    /// the closure `f`. The new elements are written into the free space in front of
+    /// the existing ones, the value returned last becomes the first element.
+    ///
+    /// If `new_len` is less than `len`, the array is simply truncated from the beginning.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from([1, 2, 3]);
+    /// cev.resize_with(5, Default::default);
+    /// assert_eq!(cev, [0, 0, 1, 2, 3]);
+    ///
+    /// let mut cev = Cev::new();
+    /// let mut p = 1;
+    /// cev.resize_with(4, || { p *= 2; p });
+    /// assert_eq!(cev, [16, 8, 4, 2]);
+    /// ```
+    pub fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where
+        F: FnMut() -> T,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.extend(iter::repeat_with(f).take(new_len - len));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// Removes all elements `e` for which `f(&e)` returns `false`.
+    /// This method operates in place, visiting each element exactly once
+    /// from the last to the first, and moves the retained elements towards the end
+    /// of the array, preserving their order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from([1, 2, 3, 4]);
+    /// cev.retain(|&x| x % 2 == 0);
+    /// assert_eq!(cev, [2, 4]);
+    /// ```
+    ///
+    /// The elements are visited from the last to the first:
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from([1, 2, 3, 4, 5]);
+    /// let keep = [false, true, true, false, true];
+    /// let mut iter = keep.iter().rev();
+    /// cev.retain(|_| *iter.next().unwrap());
+    /// assert_eq!(cev, [2, 3, 5]);
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|elem| f(elem));
+    }
+
+    /// Retains only the elements specified by the predicate, passing a mutable reference to it.
+    ///
+    /// Removes all elements `e` for which `f(&mut e)` returns `false`.
+    /// This method operates in place, visiting each element exactly once
+    /// from the last to the first, and moves the retained elements towards the end
+    /// of the array, preserving their order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from([1, 2, 3, 4]);
+    /// cev.retain_mut(|x| if *x <= 3 {
+    ///     *x += 1;
+    ///     true
+    /// } else {
+    ///     false
+    /// });
+    /// assert_eq!(cev, [2, 3, 4]);
+    /// ```
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let original_len = self.len();
+
+        if original_len == 0 {
+            return;
+        }
+
+        // The elements [0, original_len - processed_len) are unprocessed,
+        // the retained elements end at `original_len`.
+        struct BackshiftOnDrop<'a, T, A: Allocator, G: GrowthPolicy> {
+            cev: &'a mut Cev<T, A, G>,
+            base: *mut T,
+            processed_len: usize,
+            deleted_cnt: usize,
+            original_len: usize,
+        }
+
+        impl<T, A: Allocator, G: GrowthPolicy> Drop for BackshiftOnDrop<'_, T, A, G> {
+            fn drop(&mut self) {
+                unsafe {
+                    if self.deleted_cnt > 0 {
+                        ptr::copy(
+                            self.base,
+                            self.base.add(self.deleted_cnt),
+                            self.original_len - self.processed_len,
+                        );
+                    }
+                    self.cev.set_len_ptr(self.original_len - self.deleted_cnt);
+                }
+            }
+        }
+
+        let base = self.as_mut_ptr();
+        let mut g = BackshiftOnDrop {
+            cev: self,
+            base,
+            processed_len: 0,
+            deleted_cnt: 0,
+            original_len,
+        };
+
+        fn process_loop<F, T, A: Allocator, G: GrowthPolicy, const DELETED: bool>(
+            original_len: usize,
+            f: &mut F,
+            g: &mut BackshiftOnDrop<'_, T, A, G>,
+        ) where
+            F: FnMut(&mut T) -> bool,
+        {
+            while g.processed_len != original_len {
+                let index = original_len - 1 - g.processed_len;
+                let cur = unsafe { &mut *g.base.add(index) };
+                if !f(cur) {
+                    // Advance early to avoid double drop if `drop_in_place` panicked.
+                    g.processed_len += 1;
+                    g.deleted_cnt += 1;
+                    unsafe { ptr::drop_in_place(cur) };
+                    if DELETED {
+                        continue;
+                    } else {
+                        break;
+                    }
+                }
+                if DELETED {
+                    unsafe {
+                        let hole_slot = g.base.add(index + g.deleted_cnt);
+                        ptr::copy_nonoverlapping(cur, hole_slot, 1);
+                    }
+                }
+                g.processed_len += 1;
+            }
+        }
+
+        // Stage 1: Nothing was deleted.
+        process_loop::<F, T, A, G, false>(original_len, &mut f, &mut g);
+
+        // Stage 2: Some elements were deleted.
+        process_loop::<F, T, A, G, true>(original_len, &mut f, &mut g);
+
+        drop(g);
+    }
+
+    /// Changes the current length to `new_len`.
+    ///
+    /// # Safety
+    /// - Length of new_len must be initialized.
+    /// - The length must be equal to or less than the capacity.
+    ///
+    /// The first element is at capacity minus `new_len`, so the array keeps
+    /// the last `new_len` elements of the allocation.
+    ///
+    /// # Exaples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::<u8>::from([21, 32, 43]);
+    /// unsafe { cev.set_len(2) };
+    /// assert_eq!(cev, [32, 43]);
+    /// ```
+    #[inline]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= self.capacity());
+
+        self.len = new_len;
+    }
+
+    /// Changes the current length to `new_len`,
+    /// also sets the `mov_ptr` pointer to the desired position.
+    ///
+    /// The pointer is derived from the length, so this is the same as [`set_len`].
+    ///
+    /// [`set_len`]: Cev::set_len
+    ///
+    /// # Safety
+    /// - Length of new_len must be initialized.
+    /// - The length of `new_len` must not be greater than the capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from([1, 2, 3, 4, 5]);
+    ///
+    /// unsafe { cev.set_len_ptr(2) };
+    /// assert_eq!(cev, [4, 5]);
+    /// ```
+    #[inline]
+    pub unsafe fn set_len_ptr(&mut self, new_len: usize) {
+        debug_assert!(new_len <= self.capacity());
+
+        self.set_len(new_len);
+    }
+
+    #[inline]
+    unsafe fn mov_ptr(&self, new_len: usize) -> *mut T {
+        self.buf.raw_ptr().add(self.capacity() - new_len)
+    }
+
+    /// Shrinks the capacity of the `Cev` array with a lower bound.
+    /// The capacity will remain at least as large as both the length and the supplied value.
+    /// The elements stay anchored at the end of the new allocation.
+    /// If the current capacity is less than the lower limit, this is a no-op.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::with_capacity(10);
+    /// cev.push(3);
+    /// cev.push(2);
+    /// cev.push(1);
+    /// assert_eq!(cev.capacity(), 10);
+    /// cev.shrink_to(4);
+    /// assert!(cev.capacity() >= 4);
+    /// cev.shrink_to(0);
+    /// assert!(cev.capacity() >= 3);
+    /// assert_eq!(cev, [1, 2, 3]);
+    /// ```
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        if self.capacity() > min_capacity {
+            self.buf
+                .shrink_to_fit(self.len, cmp::max(self.len, min_capacity));
+        }
+    }
+
+    /// Shrinks the capacity of the `Cev` array as much as possible.
+    /// The elements stay anchored at the end of the new allocation,
+    /// an empty `Cev` array releases its memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::with_capacity(10);
+    /// cev.push(3);
+    /// cev.push(2);
+    /// cev.push(1);
+    /// assert_eq!(cev.capacity(), 10);
+    /// cev.shrink_to_fit();
+    /// assert_eq!(cev.capacity(), 3);
+    /// assert_eq!(cev.as_ptr(), cev.raw_ptr());
+    /// assert_eq!(cev, [1, 2, 3]);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        if self.capacity() > self.len {
+            self.buf.shrink_to_fit(self.len, self.len);
+        }
+    }
+
+    /// Returns the remaining spare capacity of the `Cev` array as a slice of
+    /// `MaybeUninit<T>`.
+    #[inline]
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        unsafe {
+            slice::from_raw_parts_mut(
+                self.buf.raw_ptr() as *mut MaybeUninit<T>,
+                self.buf.capacity() - self.len,
+            )
+        }
+    }
+
+    /// Creates a splicing iterator that replaces the specified range in the `Cev` array
+    /// with the given `replace_with` iterator and yields the removed items.
+    /// `replace_with` does not need to be the same length as `range`.
+    ///
+    /// `range` is removed even if the iterator is not consumed until the end.
+    ///
+    /// The input iterator `replace_with` is only consumed when the `Splice` value is dropped.
+    /// The new elements are written right after the elements before the range. If there
+    /// are more of them than removed ones, the elements before the range are moved into
+    /// the free space at the beginning of the array, which grows if needed.
+    /// The lower bound of `replace_with`'s `size_hint()` is used to make room once,
+    /// any further elements are collected first to get an exact count.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if
+    /// the end point is greater than the length of the `Cev` array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from([1, 2, 3, 4]);
+    /// let new = [7, 8, 9];
+    /// let u: Vec<_> = cev.splice(1..3, new).collect();
+    /// assert_eq!(cev, [1, 7, 8, 9, 4]);
+    /// assert_eq!(u, [2, 3]);
+    /// ```
+    #[inline]
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Splice<'_, I::IntoIter, A, G>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        Splice {
+            drain: self.drain(range),
+            replace_with: replace_with.into_iter(),
+        }
+    }
+
+    /// Splits the `Cev` array into two at the given index.
+    ///
+    /// Returns a newly allocated `Cev` array containing the elements in the range
+    /// `[at, len)`. After the call, the original array will be left containing
+    /// the elements `[0, at)` slid to its end, with its previous capacity unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from([1, 2, 3]);
+    /// let cev2 = cev.split_off(1);
+    /// assert_eq!(cev, [1]);
+    /// assert_eq!(cev2, [2, 3]);
+    /// ```
+    #[inline]
+    #[must_use = "use `.truncate()` if you don't need the other half"]
+    #[track_caller]
+    pub fn split_off(&mut self, at: usize) -> Self
+    where
+        A: Clone,
+        G: Clone,
+    {
+        #[cold]
+        #[inline(never)]
+        #[track_caller]
+        fn assert_failed(at: usize, len: usize) -> ! {
+            panic!("`at` split index (is {at}) should be <= len (is {len})");
+        }
+
+        let len = self.len();
+        if at > len {
+            assert_failed(at, len);
+        }
+
+        if at == 0 {
+            return mem::replace(self, self.with_capacity_like(self.capacity()));
+        }
+
+        let other_len = len - at;
+        let mut other = self.with_capacity_like(other_len);
+
+        unsafe {
+            let ptr = self.as_mut_ptr();
+            other.set_len_ptr(other_len);
+            ptr::copy_nonoverlapping(ptr.add(at), other.as_mut_ptr(), other_len);
+            ptr::copy(ptr, ptr.add(other_len), at);
+            self.set_len_ptr(at);
+        }
+        other
+    }
+
+    /// Splits off the first `n` elements, the most recently added ones,
+    /// into a newly allocated `Cev` array.
+    ///
+    /// After the call, the original array will be left containing the elements `[n, len)`,
+    /// which stay in place, with its previous capacity unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::new();
+    /// cev.push(3);
+    /// cev.push(2);
+    /// cev.push(1);
+    /// let front = cev.split_off_front(2);
+    /// assert_eq!(front, [1, 2]);
+    /// assert_eq!(cev, [3]);
+    /// ```
+    #[inline]
+    #[must_use = "use `.truncate()` if you don't need the other half"]
+    #[track_caller]
+    pub fn split_off_front(&mut self, n: usize) -> Self
+    where
+        A: Clone,
+        G: Clone,
+    {
+        #[cold]
+        #[inline(never)]
+        #[track_caller]
+        fn assert_failed(n: usize, len: usize) -> ! {
+            panic!("`n` split length (is {n}) should be <= len (is {len})");
+        }
+
+        let len = self.len();
+        if n > len {
+            assert_failed(n, len);
+        }
+
+        if n == len {
+            return mem::replace(self, self.with_capacity_like(self.capacity()));
+        }
+
+        let mut other = self.with_capacity_like(n);
+
+        unsafe {
+            other.set_len_ptr(n);
+            ptr::copy_nonoverlapping(self.as_ptr(), other.as_mut_ptr(), n);
+            self.set_len_ptr(len - n);
+        }
+        other
+    }
+
+    /// Removes an element from the `Cev` array and returns it.
+    ///
+    /// The removed element is replaced by the first element of the array,
+    /// which is the cheap end to remove from.
+    /// This does not preserve ordering, but is *O*(1).
+    /// If you need to preserve the element order, use [`remove`] instead.
+    ///
+    /// [`remove`]: Cev::remove
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from(["foo", "bar", "baz", "qux"]);
+    ///
+    /// assert_eq!(cev.swap_remove(2), "baz");
+    /// assert_eq!(cev, ["bar", "foo", "qux"]);
+    ///
+    /// assert_eq!(cev.swap_remove(0), "bar");
+    /// assert_eq!(cev, ["foo", "qux"]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        #[cold]
+        #[inline(never)]
+        #[track_caller]
+        fn assert_failed(index: usize, len: usize) -> ! {
+            panic!("swap_remove index (is {index}) should be < len (is {len})");
+        }
+
+        let len = self.len();
+        if index >= len {
+            assert_failed(index, len);
+        }
+
+        unsafe {
+            let ptr = self.as_mut_ptr();
+            let value = ptr::read(ptr.add(index));
+            ptr::copy(ptr, ptr.add(index), 1);
+            self.set_len_ptr(len - 1);
+            value
+        }
+    }
+
+    /// Reduces the length of the `Cev` array to `len`,
+    /// by removing elements from the beginning of the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from(['T', 'r', 'u', 'n', 'c', 'a', 't', 'e']);
+    /// cev.truncate(4);
+    /// assert_eq!(cev, ['c', 'a', 't', 'e']);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        unsafe {
+            if len >= self.len {
+                return;
+            }
+
+            let remaining_len = self.len - len;
+            let s = ptr::slice_from_raw_parts_mut(self.as_mut_ptr(), remaining_len);
+
+            self.len = len;
+            ptr::drop_in_place(s);
+        }
+    }
+
+    /// Moves all the elements of `other` to the beginning of the `Cev` array,
+    /// returning an error instead of panicking if the capacity cannot be reserved.
+    ///
+    /// On error, both `self` and `other` are left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from([4, 5, 6]);
+    /// let mut cev_other = Cev::from([1, 2, 3]);
+    /// cev.try_append(&mut cev_other).expect("why is the test harness OOMing on 6 ints?");
+    /// assert_eq!(cev, [1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(cev_other, []);
+    /// ```
+    pub fn try_append(&mut self, other: &mut Self) -> Result<(), TryReserveError> {
+        self.try_reserve(other.len())?;
+        self.append(other);
+        Ok(())
+    }
+
+    /// Inserts an element at position `index` within the `Cev` array, shifting all
+    /// elements before it to the left, returning an error instead of panicking
+    /// if the capacity cannot be reserved.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then `element`
+    /// is returned back with the error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from([2, 2, 3]);
+    /// cev.try_insert(1, 0).expect("why is the test harness OOMing on 4 ints?");
+    /// assert_eq!(cev, [2, 0, 2, 3]);
+    /// ```
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), (T, TryReserveError)> {
+        let len = self.len();
+
+        if index > len {
+            insert_assert_failed(index, len);
+        }
+
+        if len == self.buf.capacity() {
+            if let Err(err) = self.try_reserve(1) {
+                return Err((element, err));
+            }
+        }
+
+        unsafe { self.insert_unchecked(index, element) };
+        Ok(())
+    }
+
+    /// Appends an element to the beginning of a collection,
+    /// returning an error instead of panicking if the capacity cannot be reserved.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then `value`
+    /// is returned back with the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev: Cev<i8> = Cev::new();
+    /// cev.try_push(1).expect("why is the test harness OOMing on 1 int?");
+    /// cev.try_push(2).expect("why is the test harness OOMing on 2 ints?");
+    /// assert_eq!(cev, [2, 1]);
+    /// ```
+    #[inline]
+    pub fn try_push(&mut self, value: T) -> Result<(), (T, TryReserveError)> {
+        if self.len == self.capacity() {
+            if let Err(err) = self.buf.try_reserve(self.len, 1) {
+                return Err((value, err));
+            }
+        }
+        unsafe {
+            self.as_mut_ptr().sub(1).write(value);
             self.len += 1;
         };
+        Ok(())
     }
 
-    /// Returns a constant unsafe pointer.
-    /// Pointer for alloc and dealloc memory.
-    #[inline]
-    pub fn raw_ptr(&self) -> *const T {
-        self.buf.raw_ptr()
-    }
-
-    /// Reserves capacity for the elements in this collection.
+    /// Tries to reserve capacity for at least `additional` more elements.
     /// It is possible that more space will be reserved than specified.
     /// Does nothing if capacity is already sufficient.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
     ///
     /// # Examples
     ///
     /// ```
-    /// use cev::Cev;
+    /// use cev::{Cev, TryReserveErrorKind};
     ///
     /// let mut cev = Cev::from(["one"]);
-    /// cev.reserve(5);
+    /// cev.try_reserve(5).expect("why is the test harness OOMing on 6 elements?");
     /// assert!(cev.capacity() >= 6);
+    ///
+    /// let err = cev.try_reserve(usize::MAX).unwrap_err();
+    /// assert_eq!(err.kind(), TryReserveErrorKind::CapacityOverflow);
     /// ```
-    pub fn reserve(&mut self, additional: usize) {
-        self.buf.reserve(self.len, additional);
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.buf.try_reserve(self.len, additional)
     }
 
-    /// Changes the current length to `new_len`.
+    /// Tries to reserve the minimum capacity for at least `additional` more elements.
+    /// Does nothing if capacity is already sufficient.
     ///
-    /// # Safety
-    /// - Length of new_len must be initialized.
-    /// - The length must be equal to or less than the capacity.
-    /// - The `mov_ptr` **pointer must be set to the correct position**.
+    /// # Errors
     ///
-    /// # Exaples
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use cev::Cev;
     ///
-    /// let mut cev = Cev::<u8>::from([21, 32, 43]);
-    /// unsafe {
-    ///     cev.set_len(2);
-    ///     cev.as_mut_ptr().add(cev.capacity() - cev.len());
-    /// };
-    /// assert_eq!(cev, [21, 32]);
+    /// let mut cev = Cev::from(["one"]);
+    /// cev.try_reserve_exact(5).expect("why is the test harness OOMing on 6 elements?");
+    /// assert_eq!(cev.capacity(), 6);
+    /// assert_eq!(cev, ["one"]);
     /// ```
-    #[inline]
-    pub unsafe fn set_len(&mut self, new_len: usize) {
-        debug_assert!(new_len <= self.capacity());
-
-        self.len = new_len;
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.buf.try_reserve_exact(self.len, additional)
     }
 
-    /// Changes the current length to `new_len`,
-    /// also sets the `mov_ptr` pointer to the desired position.
-    ///
-    /// # Safety
-    /// - Length of new_len must be initialized.
-    /// - The length of `new_len` must not be greater than the capacity.
+    /// Replaces the growth policy of the `Cev` array, keeping its elements and memory.
     ///
     /// # Examples
     ///
     /// ```
-    /// use cev::Cev;
-    ///
-    /// let mut cev = Cev::from([1, 2, 3, 4, 5]);
+    /// use cev::{Cev, OneAndHalf};
     ///
-    /// unsafe { cev.set_len_ptr(2) };
-    /// assert_eq!(cev, [4, 5]);
+    /// let mut cev = Cev::with_capacity(10).with_growth_policy(OneAndHalf);
+    /// cev.extend(0..11);
+    /// assert_eq!(cev.capacity(), 15);
     /// ```
-    #[inline]
-    pub unsafe fn set_len_ptr(&mut self, new_len: usize) {
-        debug_assert!(new_len <= self.capacity());
+    pub fn with_growth_policy<H: GrowthPolicy>(self, policy: H) -> Cev<T, A, H> {
+        let this = ManuallyDrop::new(self);
+        Cev {
+            buf: unsafe { ptr::read(&this.buf) }.with_growth_policy(policy),
+            len: this.len,
+        }
+    }
 
-        self.buf.mov_ptr(self.mov_ptr(new_len));
-        self.len = new_len;
+    /// Creates an empty `Cev` array with the allocator and the growth policy of `self`.
+    fn with_capacity_like(&self, capacity: usize) -> Self
+    where
+        A: Clone,
+        G: Clone,
+    {
+        Cev::with_capacity_in(capacity, self.allocator().clone())
+            .with_growth_policy(self.growth_policy().clone())
     }
 
+    /// Appends elements to `self` from other buffer.
+    ///
+    /// The elements are copied bitwise in front of the existing ones with a single reserve,
+    /// so unless `T` is `Copy` the caller gives up ownership of them.
     #[inline]
-    unsafe fn mov_ptr(&self, new_len: usize) -> *mut T {
-        if self.capacity() == new_len {
-            self.buf.raw_ptr()
-        } else if new_len == 0 {
-            self.buf.raw_ptr().add(self.capacity() - 1)
-        } else {
-            self.buf.raw_ptr().add(self.capacity() - new_len)
+    pub(crate) unsafe fn append_elements(&mut self, other: *const [T]) {
+        let count = other.len();
+        self.reserve(count);
+        self.set_len_ptr(self.len() + count);
+        ptr::copy_nonoverlapping(other as *const T, self.as_mut_ptr(), count);
+    }
+
+    /// Pushes every item of `iter` to the front like [`Extend::extend`], but fills
+    /// the spare capacity chunk by chunk instead of checking it for every item.
+    fn extend_front_chunked<I: Iterator<Item = T>>(&mut self, mut iter: I) {
+        struct SetLenOnDrop<'a, T, A: Allocator, G: GrowthPolicy> {
+            cev: &'a mut Cev<T, A, G>,
+            written: usize,
+        }
+
+        impl<T, A: Allocator, G: GrowthPolicy> Drop for SetLenOnDrop<'_, T, A, G> {
+            fn drop(&mut self) {
+                unsafe { self.cev.set_len_ptr(self.cev.len() + self.written) };
+            }
+        }
+
+        loop {
+            let spare = self.capacity() - self.len();
+            if spare == 0 {
+                match iter.next() {
+                    Some(elem) => self.push(elem),
+                    None => return,
+                }
+                continue;
+            }
+
+            let end = self.as_mut_ptr();
+            let mut guard = SetLenOnDrop {
+                cev: self,
+                written: 0,
+            };
+            for elem in iter.by_ref().take(spare) {
+                guard.written += 1;
+                unsafe { end.sub(guard.written).write(elem) };
+            }
+            if guard.written < spare {
+                return;
+            }
         }
     }
 
-    /// Returns the remaining spare capacity of the `Cev` array as a slice of
-    /// `MaybeUninit<T>`.
+    /// Writes up to `count` items of `iter` in order directly in front of the elements.
+    /// If the iterator ends early or panics, the written items are moved next to the elements.
+    fn extend_front_exact<I: Iterator<Item = T>>(&mut self, iter: &mut I, count: usize) {
+        struct CloseGapOnDrop<'a, T, A: Allocator, G: GrowthPolicy> {
+            cev: &'a mut Cev<T, A, G>,
+            dst: *mut T,
+            count: usize,
+            written: usize,
+        }
+
+        impl<T, A: Allocator, G: GrowthPolicy> Drop for CloseGapOnDrop<'_, T, A, G> {
+            fn drop(&mut self) {
+                unsafe {
+                    let gap = self.count - self.written;
+                    if gap != 0 {
+                        ptr::copy(self.dst, self.dst.add(gap), self.written);
+                    }
+                    self.cev.set_len_ptr(self.cev.len() + self.written);
+                }
+            }
+        }
+
+        self.reserve(count);
+        let dst = unsafe { self.as_mut_ptr().sub(count) };
+        let mut guard = CloseGapOnDrop {
+            cev: self,
+            dst,
+            count,
+            written: 0,
+        };
+
+        iter.take(count).for_each(|elem| {
+            unsafe { guard.dst.add(guard.written).write(elem) };
+            guard.written += 1;
+        });
+    }
+
+    /// Inserts `first` and the rest of `iter` in order, starting at `index`.
+    #[cold]
+    fn insert_remaining<I: Iterator<Item = T>>(&mut self, mut index: usize, first: T, iter: I) {
+        self.insert(index, first);
+        for elem in iter {
+            index += 1;
+            self.insert(index, elem);
+        }
+    }
+
+    /// Writes `element` at `index`, shifting the elements before it to the left.
+    /// The capacity must already be sufficient and `index <= len`.
     #[inline]
-    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
-        unsafe {
-            slice::from_raw_parts_mut(
-                self.buf.raw_ptr() as *mut MaybeUninit<T>,
-                self.buf.capacity() - self.len,
-            )
+    unsafe fn insert_unchecked(&mut self, index: usize, element: T) {
+        let len = self.len();
+        let p = self.as_mut_ptr();
+        ptr::copy(p, p.sub(1), index);
+        ptr::write(p.sub(1).add(index), element);
+        self.set_len(len + 1);
+    }
+}
+
+/// Converts `range` into the bounds of a slice of length `len`.
+///
+/// # Panics
+///
+/// Panics if the starting point is greater than the end point or if
+/// the end point is greater than `len`.
+fn slice_range<R>(range: R, len: usize) -> ops::Range<usize>
+where
+    R: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(start) => start
+            .checked_add(1)
+            .unwrap_or_else(|| panic!("attempted to index slice from after maximum usize")),
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(end) => end
+            .checked_add(1)
+            .unwrap_or_else(|| panic!("attempted to index slice up to maximum usize")),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    if start > end {
+        panic!("slice index starts at {start} but ends at {end}");
+    }
+    if end > len {
+        panic!("range end index {end} out of range for slice of length {len}");
+    }
+
+    start..end
+}
+
+#[cold]
+#[inline(never)]
+fn insert_assert_failed(index: usize, len: usize) -> ! {
+    panic!("insertion index (is {index}) should be <= len (is {len})");
+}
+
+impl<T: Clone, A: Allocator, G: GrowthPolicy> Cev<T, A, G> {
+    /// Clones the elements of `range` and places the copies at the beginning of the `Cev` array,
+    /// keeping their order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if
+    /// the end point is greater than the length of the `Cev` array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from(['a', 'b', 'c', 'd']);
+    /// cev.prepend_from_within(2..);
+    /// assert_eq!(cev, ['c', 'd', 'a', 'b', 'c', 'd']);
+    ///
+    /// cev.prepend_from_within(..1);
+    /// assert_eq!(cev, ['c', 'c', 'd', 'a', 'b', 'c', 'd']);
+    /// ```
+    pub fn prepend_from_within<R>(&mut self, range: R)
+    where
+        R: RangeBounds<usize>,
+    {
+        let ops::Range { start, end } = slice_range(range, self.len());
+        self.reserve(end - start);
+
+        for (pushed, index) in (start..end).rev().enumerate() {
+            let elem = self[index + pushed].clone();
+            self.push(elem);
         }
     }
 
-    /// Reduces the length of the `Cev` array to `len`,
-    /// by removing elements from the beginning of the array.
+    /// Clones all elements of the slice and places them at the beginning of the `Cev` array,
+    /// keeping their order.
     ///
     /// # Examples
     ///
     /// ```
     /// use cev::Cev;
     ///
-    /// let mut cev = Cev::from(['T', 'r', 'u', 'n', 'c', 'a', 't', 'e']);
-    /// cev.truncate(4);
-    /// assert_eq!(cev, ['c', 'a', 't', 'e']);
+    /// let mut cev = Cev::from([4, 5]);
+    /// cev.prepend_slice(&[1, 2, 3]);
+    /// assert_eq!(cev, [1, 2, 3, 4, 5]);
     /// ```
-    pub fn truncate(&mut self, len: usize) {
-        unsafe {
-            if len >= self.len {
-                return;
-            }
+    pub fn prepend_slice(&mut self, other: &[T]) {
+        struct SetLenOnDrop<'a, T, A: Allocator, G: GrowthPolicy> {
+            cev: &'a mut Cev<T, A, G>,
+            written: usize,
+        }
 
-            let remaining_len = self.len - len;
-            let s = ptr::slice_from_raw_parts_mut(self.as_mut_ptr(), remaining_len);
-            if len != 0 {
-                self.buf.mov_ptr_add(remaining_len);
-            } else {
-                self.buf.mov_ptr_add(remaining_len - 1);
+        impl<T, A: Allocator, G: GrowthPolicy> Drop for SetLenOnDrop<'_, T, A, G> {
+            fn drop(&mut self) {
+                unsafe { self.cev.set_len_ptr(self.cev.len() + self.written) };
             }
+        }
 
-            self.len = len;
-            ptr::drop_in_place(s);
+        self.reserve(other.len());
+        let end = self.as_mut_ptr();
+        let mut guard = SetLenOnDrop {
+            cev: self,
+            written: 0,
+        };
+
+        // Cloned back to front, so the written elements always border the old ones.
+        for elem in other.iter().rev() {
+            unsafe { end.sub(guard.written + 1).write(elem.clone()) };
+            guard.written += 1;
         }
     }
 
-    /// Creates a new `Cev` array and allocates memory for type `T` with the given capacity.
-    /// If `capacity` is null, no memory is allocated.
+    /// Resizes the `Cev` array in-place so that `len` is equal to `new_len`.
     ///
-    /// # Panics
+    /// If `new_len` is greater than `len`, the `Cev` array is extended at the beginning
+    /// by the difference, with each additional slot filled with `value`.
+    /// If `new_len` is less than `len`, the array is simply truncated from the beginning.
     ///
-    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    /// This method requires `T` to implement [`Clone`],
+    /// in order to be able to clone the passed value.
     ///
     /// # Examples
     ///
     /// ```
     /// use cev::Cev;
-    /// use std::ptr::NonNull;
-    ///
-    /// let mut cev = Cev::with_capacity(0);
-    /// assert_eq!(cev.as_ptr(), NonNull::<u32>::dangling().as_ptr());
-    ///
-    /// let capacity = 5;
-    /// let mut cev = Cev::with_capacity(capacity);
     ///
-    /// assert!(cev.capacity() >= capacity);
-    ///
-    /// for val in 0..=capacity {
-    ///     cev.insert(0, val);
-    /// }
-    /// assert_eq!(cev, [5, 4, 3, 2, 1, 0]);
+    /// let mut cev = Cev::from(["1", "2"]);
+    /// cev.resize(4, "0");
+    /// assert_eq!(cev, ["0", "0", "1", "2"]);
     ///
+    /// let mut cev = Cev::from([1, 2, 3, 4]);
+    /// cev.resize(2, 0);
+    /// assert_eq!(cev, [3, 4]);
     /// ```
-    #[inline]
-    pub fn with_capacity(capacity: usize) -> Self {
-        Cev {
-            buf: RawCev::with_capacity(capacity),
-            len: 0,
+    pub fn resize(&mut self, new_len: usize, value: T) {
+        let len = self.len();
+        if new_len > len {
+            let additional = new_len - len;
+            self.reserve(additional);
+
+            for _ in 1..additional {
+                self.push(value.clone());
+            }
+            self.push(value);
+        } else {
+            self.truncate(new_len);
         }
     }
+}
 
-    /// Appends elements to `self` from other buffer.
+impl<T: PartialEq, A: Allocator, G: GrowthPolicy> Cev<T, A, G> {
+    /// Removes consecutive repeated elements in the `Cev` array according to the
+    /// [`PartialEq`] trait implementation.
+    ///
+    /// If the array is sorted, this removes all duplicates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from([1, 2, 2, 3, 2]);
+    /// cev.dedup();
+    /// assert_eq!(cev, [1, 2, 3, 2]);
+    /// ```
     #[inline]
-    unsafe fn append_elements(&mut self, other: *const [T]) {
-        let count = (*other).len();
-        self.reserve(count);
-        self.set_len_ptr(self.len() + count);
-        ptr::copy_nonoverlapping(other as *const T, self.as_mut_ptr(), count);
+    pub fn dedup(&mut self) {
+        self.dedup_by(|a, b| a == b)
     }
 }
 
-impl<T> AsRef<Cev<T>> for Cev<T> {
-    fn as_ref(&self) -> &Cev<T> {
+impl<T, A: Allocator, G: GrowthPolicy> AsRef<Cev<T, A, G>> for Cev<T, A, G> {
+    fn as_ref(&self) -> &Cev<T, A, G> {
         self
     }
 }
 
-impl<T> AsMut<Cev<T>> for Cev<T> {
-    fn as_mut(&mut self) -> &mut Cev<T> {
+impl<T, A: Allocator, G: GrowthPolicy> AsMut<Cev<T, A, G>> for Cev<T, A, G> {
+    fn as_mut(&mut self) -> &mut Cev<T, A, G> {
         self
     }
 }
 
-impl<T> Borrow<[T]> for Cev<T> {
+impl<T, A: Allocator, G: GrowthPolicy> Borrow<[T]> for Cev<T, A, G> {
     fn borrow(&self) -> &[T] {
         &self[..]
     }
 }
 
-impl<T> BorrowMut<[T]> for Cev<T> {
+impl<T, A: Allocator, G: GrowthPolicy> BorrowMut<[T]> for Cev<T, A, G> {
     fn borrow_mut(&mut self) -> &mut [T] {
         &mut self[..]
     }
 }
 
-impl<T> AsRef<[T]> for Cev<T> {
+impl<T, A: Allocator, G: GrowthPolicy> AsRef<[T]> for Cev<T, A, G> {
     fn as_ref(&self) -> &[T] {
         self
     }
 }
 
-impl<T> AsMut<[T]> for Cev<T> {
+impl<T, A: Allocator, G: GrowthPolicy> AsMut<[T]> for Cev<T, A, G> {
     fn as_mut(&mut self) -> &mut [T] {
         self
     }
 }
 
-impl<T> ops::Deref for Cev<T> {
+impl<T, A: Allocator, G: GrowthPolicy> ops::Deref for Cev<T, A, G> {
     type Target = [T];
 
     #[inline]
@@ -624,88 +2147,82 @@ impl<T> ops::Deref for Cev<T> {
     }
 }
 
-impl<T> ops::DerefMut for Cev<T> {
+impl<T, A: Allocator, G: GrowthPolicy> ops::DerefMut for Cev<T, A, G> {
     #[inline]
     fn deref_mut(&mut self) -> &mut [T] {
         unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) }
     }
 }
 
-impl<T> Drop for Cev<T> {
+impl<T, A: Allocator, G: GrowthPolicy> Drop for Cev<T, A, G> {
     fn drop(&mut self) {
         unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.as_mut_ptr(), self.len)) }
     }
 }
 
-impl<T: fmt::Debug> fmt::Debug for Cev<T> {
+impl<T: fmt::Debug, A: Allocator, G: GrowthPolicy> fmt::Debug for Cev<T, A, G> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Debug::fmt(&**self, f)
     }
 }
 
-macro_rules! impl_slice_eq {
-    ([$($vars:tt)*] $lhs:ty, $rhs:ty $(where $ty:ty: $bound:ident)?) => {
-        impl<T, U, $($vars)*> PartialEq<$rhs> for $lhs
-        where
-            T: PartialEq<U>,
-            $($ty: $bound)?
-        {
-            #[inline]
-            fn eq(&self, other: &$rhs) -> bool { self[..] == other[..] }
-            #[inline]
-            fn ne(&self, other: &$rhs) -> bool { self[..] != other[..] }
-        }
-    }
-}
-
-impl_slice_eq! { [] Cev<T>, &[U]}
-impl_slice_eq! { [const N: usize] Cev<T>, [U; N] }
-impl_slice_eq! { [const N: usize] Cev<T>, &[U; N] }
-impl_slice_eq! { [] Cev<T>, &mut [U] }
-impl_slice_eq! { [] Cev<T>, [U] }
-impl_slice_eq! { [] [T], Cev<U> }
-impl_slice_eq! { [] &[T], Cev<U> }
-impl_slice_eq! { [] &mut [T], Cev<U> }
-impl_slice_eq! { [] Cev<T>, Cev<U> }
-impl_slice_eq! { [] Cev<T>, Vec<U> }
-impl_slice_eq! { [] Vec<T>, Cev<U> }
-
-impl<T: PartialOrd> PartialOrd for Cev<T> {
+impl_slice_eq! { [A: Allocator, G: GrowthPolicy] Cev<T, A, G>, &[U]}
+impl_slice_eq! { [A: Allocator, G: GrowthPolicy, const N: usize] Cev<T, A, G>, [U; N] }
+impl_slice_eq! { [A: Allocator, G: GrowthPolicy, const N: usize] Cev<T, A, G>, &[U; N] }
+impl_slice_eq! { [A: Allocator, G: GrowthPolicy] Cev<T, A, G>, &mut [U] }
+impl_slice_eq! { [A: Allocator, G: GrowthPolicy] Cev<T, A, G>, [U] }
+impl_slice_eq! { [A: Allocator, G: GrowthPolicy] [T], Cev<U, A, G> }
+impl_slice_eq! { [A: Allocator, G: GrowthPolicy] &[T], Cev<U, A, G> }
+impl_slice_eq! { [A: Allocator, G: GrowthPolicy] &mut [T], Cev<U, A, G> }
+impl_slice_eq! { [A1: Allocator, A2: Allocator, G1: GrowthPolicy, G2: GrowthPolicy] Cev<T, A1, G1>, Cev<U, A2, G2> }
+impl_slice_eq! { [A: Allocator, G: GrowthPolicy] Cev<T, A, G>, Vec<U> }
+impl_slice_eq! { [A: Allocator, G: GrowthPolicy] Vec<T>, Cev<U, A, G> }
+
+impl<T: PartialOrd, A: Allocator, G: GrowthPolicy> PartialOrd for Cev<T, A, G> {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         PartialOrd::partial_cmp(&**self, &**other)
     }
 }
 
-impl<T: Eq> Eq for Cev<T> {}
+impl<T: Eq, A: Allocator, G: GrowthPolicy> Eq for Cev<T, A, G> {}
 
-impl<T: Ord> Ord for Cev<T> {
+impl<T: Ord, A: Allocator, G: GrowthPolicy> Ord for Cev<T, A, G> {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
         Ord::cmp(&**self, &**other)
     }
 }
 
+impl<T: Hash, A: Allocator, G: GrowthPolicy> Hash for Cev<T, A, G> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&**self, state)
+    }
+}
+
 impl<T> Default for Cev<T> {
     fn default() -> Cev<T> {
         Cev::new()
     }
 }
 
-impl<T> Clone for Cev<T>
+impl<T, A, G> Clone for Cev<T, A, G>
 where
     T: Clone,
+    A: Allocator + Clone,
+    G: GrowthPolicy + Clone,
 {
     fn clone(&self) -> Self {
-        to_cev(self)
+        to_cev(self, self.allocator().clone()).with_growth_policy(self.growth_policy().clone())
     }
 }
 
-pub fn to_cev<T: ConvertCev>(s: &[T]) -> Cev<T> {
-    T::to_cev(s)
+pub fn to_cev<T: ConvertCev, A: Allocator>(s: &[T], alloc: A) -> Cev<T, A> {
+    T::to_cev(s, alloc)
 }
 
-impl<T, I: SliceIndex<[T]>> Index<I> for Cev<T> {
+impl<T, I: SliceIndex<[T]>, A: Allocator, G: GrowthPolicy> Index<I> for Cev<T, A, G> {
     type Output = I::Output;
 
     #[inline]
@@ -714,23 +2231,23 @@ impl<T, I: SliceIndex<[T]>> Index<I> for Cev<T> {
     }
 }
 
-impl<T, I: SliceIndex<[T]>> IndexMut<I> for Cev<T> {
+impl<T, I: SliceIndex<[T]>, A: Allocator, G: GrowthPolicy> IndexMut<I> for Cev<T, A, G> {
     #[inline]
     fn index_mut(&mut self, index: I) -> &mut Self::Output {
         IndexMut::index_mut(&mut **self, index)
     }
 }
 
-impl<T, const N: usize> TryFrom<Cev<T>> for [T; N] {
-    type Error = Cev<T>;
+impl<T, A: Allocator, G: GrowthPolicy, const N: usize> TryFrom<Cev<T, A, G>> for [T; N] {
+    type Error = Cev<T, A, G>;
 
-    fn try_from(mut cev: Cev<T>) -> Result<[T; N], Cev<T>> {
+    fn try_from(mut cev: Cev<T, A, G>) -> Result<[T; N], Cev<T, A, G>> {
         if cev.len() != N {
             return Err(cev);
         }
 
-        unsafe { cev.set_len(0) };
         let array = unsafe { ptr::read(cev.as_ptr() as *const [T; N]) };
+        unsafe { cev.set_len(0) };
         Ok(array)
     }
 }
@@ -758,7 +2275,7 @@ where
     T: Clone,
 {
     fn from(slice: &[T]) -> Cev<T> {
-        to_cev(slice)
+        to_cev(slice, Global)
     }
 }
 
@@ -767,36 +2284,173 @@ where
     T: Clone,
 {
     fn from(slice: &mut [T]) -> Cev<T> {
-        to_cev(slice)
+        to_cev(slice, Global)
+    }
+}
+
+impl<T> From<Cev<T>> for Box<[T]> {
+    /// Converts the `Cev` array into a boxed slice.
+    /// The allocation is reused, any excess capacity is dropped.
+    fn from(cev: Cev<T>) -> Box<[T]> {
+        cev.into_boxed_slice()
+    }
+}
+
+impl<T> From<Box<[T]>> for Cev<T> {
+    /// Converts a boxed slice into a `Cev` array by transferring ownership of
+    /// the existing heap allocation, without copying.
+    fn from(slice: Box<[T]>) -> Cev<T> {
+        Cev::from_vec(slice.into_vec())
+    }
+}
+
+impl<T> From<Cev<T>> for VecDeque<T> {
+    /// Converts the `Cev` array into a `VecDeque<T>`.
+    /// The allocation is reused, the data is moved as [`Cev::into_vec`] does.
+    fn from(cev: Cev<T>) -> VecDeque<T> {
+        VecDeque::from(cev.into_vec())
+    }
+}
+
+impl<T> From<VecDeque<T>> for Cev<T> {
+    /// Converts a `VecDeque<T>` into a `Cev` array.
+    /// The allocation is reused, the data is moved to make it contiguous
+    /// and then to the end of the allocation as [`Cev::from_vec`] does.
+    fn from(deque: VecDeque<T>) -> Cev<T> {
+        Cev::from_vec(Vec::from(deque))
+    }
+}
+
+impl<T> From<Cev<T>> for Rc<[T]> {
+    /// Allocates a reference-counted slice and moves the elements of the `Cev` array into it.
+    fn from(cev: Cev<T>) -> Rc<[T]> {
+        Rc::from(cev.into_vec())
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> From<Cev<T>> for Arc<[T]> {
+    /// Allocates an atomically reference-counted slice and moves the elements
+    /// of the `Cev` array into it.
+    fn from(cev: Cev<T>) -> Arc<[T]> {
+        Arc::from(cev.into_vec())
+    }
+}
+
+impl<'a, T: Clone> From<Cow<'a, [T]>> for Cev<T> {
+    /// Converts a clone-on-write slice into a `Cev` array.
+    /// If the slice is owned, its allocation is reused, otherwise the elements are cloned.
+    fn from(cow: Cow<'a, [T]>) -> Cev<T> {
+        match cow {
+            Cow::Borrowed(slice) => to_cev(slice, Global),
+            Cow::Owned(vec) => Cev::from_vec(vec),
+        }
+    }
+}
+
+impl<'a, T: Clone> From<Cev<T>> for Cow<'a, [T]> {
+    /// Converts the `Cev` array into an owned `Cow`, reusing the allocation.
+    fn from(cev: Cev<T>) -> Cow<'a, [T]> {
+        Cow::Owned(cev.into_vec())
+    }
+}
+
+impl<'a, T: Clone> From<&'a Cev<T>> for Cow<'a, [T]> {
+    /// Creates a borrowed `Cow` from a reference to the `Cev` array.
+    fn from(cev: &'a Cev<T>) -> Cow<'a, [T]> {
+        Cow::Borrowed(cev.as_slice())
     }
 }
 
 pub trait ConvertCev {
-    fn to_cev(s: &[Self]) -> Cev<Self>
+    fn to_cev<A: Allocator>(s: &[Self], alloc: A) -> Cev<Self, A>
     where
         Self: Sized;
 }
 
 impl<T> FromIterator<T> for Cev<T> {
+    /// Collects the items in the order they were yielded, see [`Cev::extend_front_ordered`].
+    ///
+    /// With an exact `size_hint` the elements are written directly into their final
+    /// positions at the end of a single allocation. A double-ended iterator can take
+    /// the same single pass without it through [`Cev::from_iter_rev`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let cev: Cev<_> = (1..=4).map(|x| x * 10).collect();
+    /// assert_eq!(cev, [10, 20, 30, 40]);
+    /// assert_eq!(cev.capacity(), 4);
+    /// ```
     #[inline]
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Cev<T> {
-        Self::from_vec(iter.into_iter().collect::<Vec<_>>())
+        let mut cev = Cev::new();
+        cev.extend_front_ordered(iter);
+        cev
+    }
+}
+
+impl<T, A: Allocator, G: GrowthPolicy> Extend<T> for Cev<T, A, G> {
+    /// Pushes every item of the iterator to the beginning of the `Cev` array,
+    /// so the items end up in reversed order.
+    /// Use [`Cev::extend_front_ordered`] to keep the order of the items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from([0]);
+    /// cev.extend([1, 2, 3]);
+    /// assert_eq!(cev, [3, 2, 1, 0]);
+    /// ```
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        self.reserve(lower);
+
+        for elem in iter {
+            self.push(elem);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a, A: Allocator, G: GrowthPolicy> Extend<&'a T> for Cev<T, A, G> {
+    /// Pushes a copy of every item of the iterator to the beginning of the `Cev` array,
+    /// so the items end up in reversed order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from([0]);
+    /// cev.extend(&[1, 2, 3]);
+    /// assert_eq!(cev, [3, 2, 1, 0]);
+    /// ```
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
     }
 }
 
-impl<T> IntoIterator for Cev<T> {
+impl<T, A: Allocator, G: GrowthPolicy> IntoIterator for Cev<T, A, G> {
     type Item = T;
-    type IntoIter = IntoIter<T>;
+    type IntoIter = IntoIter<T, A>;
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
         let cev = ManuallyDrop::new(self);
+        let alloc = ManuallyDrop::new(unsafe { ptr::read(cev.allocator()) });
+        drop(unsafe { ptr::read(cev.growth_policy()) });
         let ptr = cev.as_ptr();
         let len = cev.len();
 
         IntoIter {
             buf: unsafe { NonNull::new_unchecked(cev.buf.raw_ptr()) },
             cap: cev.capacity(),
+            alloc,
             len,
             ptr,
             end: unsafe { ptr.add(len) },
@@ -804,7 +2458,7 @@ impl<T> IntoIterator for Cev<T> {
     }
 }
 
-impl<'a, T> IntoIterator for &'a Cev<T> {
+impl<'a, T, A: Allocator, G: GrowthPolicy> IntoIterator for &'a Cev<T, A, G> {
     type Item = &'a T;
     type IntoIter = slice::Iter<'a, T>;
 
@@ -813,7 +2467,7 @@ impl<'a, T> IntoIterator for &'a Cev<T> {
     }
 }
 
-impl<'a, T> IntoIterator for &'a mut Cev<T> {
+impl<'a, T, A: Allocator, G: GrowthPolicy> IntoIterator for &'a mut Cev<T, A, G> {
     type Item = &'a mut T;
     type IntoIter = slice::IterMut<'a, T>;
 
@@ -823,13 +2477,13 @@ impl<'a, T> IntoIterator for &'a mut Cev<T> {
 }
 
 impl<T: Clone> ConvertCev for T {
-    fn to_cev(s: &[Self]) -> Cev<Self> {
-        struct DropGuard<'a, T> {
-            cev: &'a mut Cev<T>,
+    fn to_cev<A: Allocator>(s: &[Self], alloc: A) -> Cev<Self, A> {
+        struct DropGuard<'a, T, A: Allocator> {
+            cev: &'a mut Cev<T, A>,
             num_init: usize,
         }
 
-        impl<'a, T> Drop for DropGuard<'a, T> {
+        impl<'a, T, A: Allocator> Drop for DropGuard<'a, T, A> {
             fn drop(&mut self) {
                 unsafe {
                     self.cev.set_len(self.num_init);
@@ -837,36 +2491,35 @@ impl<T: Clone> ConvertCev for T {
             }
         }
 
-        let mut cev = Cev::with_capacity(s.len());
+        let mut cev = Cev::with_capacity_in(s.len(), alloc);
         let mut guard = DropGuard {
             cev: &mut cev,
             num_init: 0,
         };
 
+        // Filled back to front, so the initialized elements are always the last `num_init`.
         let slots = guard.cev.spare_capacity_mut();
-        for (i, b) in s.iter().enumerate().take(slots.len()) {
-            guard.num_init = i;
-            slots[i].write(b.clone());
+        for (slot, b) in slots.iter_mut().zip(s).rev() {
+            slot.write(b.clone());
+            guard.num_init += 1;
         }
 
         core::mem::forget(guard);
-        unsafe {
-            cev.set_len(s.len());
-            cev.buf.mov_ptr(cev.buf.raw_ptr());
-        }
+        unsafe { cev.set_len(s.len()) };
         cev
     }
 }
 
-pub struct IntoIter<T> {
+pub struct IntoIter<T, A: Allocator = Global> {
     buf: NonNull<T>,
     cap: usize,
+    alloc: ManuallyDrop<A>,
     len: usize,
     ptr: *const T,
     end: *const T,
 }
 
-impl<T> IntoIter<T> {
+impl<T, A: Allocator> IntoIter<T, A> {
     pub fn as_slice(&self) -> &[T] {
         unsafe { slice::from_raw_parts(self.ptr, self.len()) }
     }
@@ -880,19 +2533,19 @@ impl<T> IntoIter<T> {
     }
 }
 
-impl<T> AsRef<[T]> for IntoIter<T> {
+impl<T, A: Allocator> AsRef<[T]> for IntoIter<T, A> {
     fn as_ref(&self) -> &[T] {
         self.as_slice()
     }
 }
 
-impl<T> Default for IntoIter<T> {
+impl<T, A: Allocator + Default> Default for IntoIter<T, A> {
     fn default() -> Self {
-        Cev::new().into_iter()
+        Cev::new_in(A::default()).into_iter()
     }
 }
 
-impl<T> Iterator for IntoIter<T> {
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
     type Item = T;
 
     #[inline]
@@ -915,18 +2568,17 @@ impl<T> Iterator for IntoIter<T> {
     }
 }
 
-impl<T> Drop for IntoIter<T> {
+impl<T, A: Allocator> Drop for IntoIter<T, A> {
     fn drop(&mut self) {
-        if self.cap != 0 {
-            for _ in &mut *self {}
-            unsafe {
-                let _ = RawCev::from_raw_parts_ptr(self.buf.as_ptr(), self.buf.as_ptr(), self.cap);
-            }
+        for _ in &mut *self {}
+        unsafe {
+            let alloc = ManuallyDrop::take(&mut self.alloc);
+            let _ = RawCev::from_raw_parts_ptr_in(self.buf.as_ptr(), self.cap, alloc);
         }
     }
 }
 
-impl<T> DoubleEndedIterator for IntoIter<T> {
+impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
     #[inline]
     fn next_back(&mut self) -> Option<T> {
         if self.len == 0 {
@@ -941,10 +2593,10 @@ impl<T> DoubleEndedIterator for IntoIter<T> {
     }
 }
 
-impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for IntoIter<T, A> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("IntoIter").field(&self.as_slice()).finish()
     }
 }
 
-impl<T> ExactSizeIterator for IntoIter<T> {}
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
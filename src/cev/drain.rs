@@ -0,0 +1,177 @@
+use crate::cev::Cev;
+use crate::growth::{Doubling, GrowthPolicy};
+use allocator_api2::alloc::{Allocator, Global};
+use core::fmt;
+use core::iter::FusedIterator;
+use core::mem::{self, ManuallyDrop};
+use core::ptr::{self, NonNull};
+use core::slice;
+
+/// A draining iterator for `Cev<T>`.
+///
+/// This `struct` is created by [`Cev::drain`].
+/// While the iterator is alive, the `Cev` array holds only the elements after the drained range.
+/// The elements before the range are slid towards the end of the array when the iterator is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use cev::{Cev, Drain};
+///
+/// let mut cev = Cev::from([0, 1, 2]);
+/// let iter: Drain<'_, _> = cev.drain(..);
+/// ```
+pub struct Drain<'a, T: 'a, A: Allocator + 'a = Global, G: GrowthPolicy + 'a = Doubling> {
+    /// Offset from `raw_ptr` of the first element before the drained range.
+    pub(super) head_start: usize,
+    /// Number of elements before the drained range.
+    pub(super) head_len: usize,
+    /// Current remaining range to remove.
+    pub(super) iter: slice::Iter<'a, T>,
+    pub(super) cev: NonNull<Cev<T, A, G>>,
+}
+
+impl<T: fmt::Debug, A: Allocator, G: GrowthPolicy> fmt::Debug for Drain<'_, T, A, G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Drain").field(&self.iter.as_slice()).finish()
+    }
+}
+
+impl<'a, T, A: Allocator, G: GrowthPolicy> Drain<'a, T, A, G> {
+    /// Returns the remaining items of this iterator as a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from(['a', 'b', 'c']);
+    /// let mut drain = cev.drain(..);
+    /// assert_eq!(drain.as_slice(), &['a', 'b', 'c']);
+    /// let _ = drain.next().unwrap();
+    /// assert_eq!(drain.as_slice(), &['b', 'c']);
+    /// ```
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        self.iter.as_slice()
+    }
+
+    /// Keep unyielded elements in the source `Cev` array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from(['a', 'b', 'c']);
+    /// let mut drain = cev.drain(..);
+    ///
+    /// assert_eq!(drain.next().unwrap(), 'a');
+    ///
+    /// // This call keeps 'b' and 'c' in the array.
+    /// drain.keep_rest();
+    ///
+    /// // If we wouldn't call `keep_rest()`,
+    /// // `cev` would be empty.
+    /// assert_eq!(cev, ['b', 'c']);
+    /// ```
+    pub fn keep_rest(self) {
+        let mut this = ManuallyDrop::new(self);
+
+        unsafe {
+            let head_start = this.head_start;
+            let head_len = this.head_len;
+            let unyielded_len = this.iter.len();
+            let unyielded_ptr = this.iter.as_slice().as_ptr();
+
+            let cev = this.cev.as_mut();
+            let tail_len = cev.len();
+            let new_len = head_len + unyielded_len + tail_len;
+            let raw_ptr = cev.buf.raw_ptr();
+            let rest_ptr = raw_ptr.add(cev.capacity() - tail_len - unyielded_len);
+
+            ptr::copy(unyielded_ptr, rest_ptr, unyielded_len);
+            ptr::copy(raw_ptr.add(head_start), rest_ptr.sub(head_len), head_len);
+            cev.set_len_ptr(new_len);
+        }
+    }
+
+    /// Slides the elements before the drained range to the elements after it.
+    unsafe fn join_head(&mut self) {
+        let cev = self.cev.as_mut();
+        let new_len = cev.len() + self.head_len;
+
+        if self.head_len != 0 {
+            let raw_ptr = cev.buf.raw_ptr();
+            let dst = raw_ptr.add(cev.capacity() - new_len);
+            ptr::copy(raw_ptr.add(self.head_start), dst, self.head_len);
+        }
+        cev.set_len_ptr(new_len);
+    }
+}
+
+impl<'a, T, A: Allocator, G: GrowthPolicy> AsRef<[T]> for Drain<'a, T, A, G> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+unsafe impl<T: Sync, A: Allocator + Sync, G: GrowthPolicy + Sync> Sync for Drain<'_, T, A, G> {}
+unsafe impl<T: Send, A: Allocator + Send, G: GrowthPolicy + Send> Send for Drain<'_, T, A, G> {}
+
+impl<T, A: Allocator, G: GrowthPolicy> Iterator for Drain<'_, T, A, G> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter
+            .next()
+            .map(|elt| unsafe { ptr::read(elt as *const _) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, A: Allocator, G: GrowthPolicy> DoubleEndedIterator for Drain<'_, T, A, G> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.iter
+            .next_back()
+            .map(|elt| unsafe { ptr::read(elt as *const _) })
+    }
+}
+
+impl<T, A: Allocator, G: GrowthPolicy> Drop for Drain<'_, T, A, G> {
+    fn drop(&mut self) {
+        /// Moves back the un-`Drain`ed elements to restore the original `Cev` array.
+        struct DropGuard<'r, 'a, T, A: Allocator, G: GrowthPolicy>(&'r mut Drain<'a, T, A, G>);
+
+        impl<'r, 'a, T, A: Allocator, G: GrowthPolicy> Drop for DropGuard<'r, 'a, T, A, G> {
+            fn drop(&mut self) {
+                unsafe { self.0.join_head() };
+            }
+        }
+
+        let iter = mem::take(&mut self.iter);
+        let drop_len = iter.len();
+        let drop_ptr = iter.as_slice().as_ptr();
+
+        let _guard = DropGuard(self);
+
+        if drop_len == 0 {
+            return;
+        }
+
+        unsafe {
+            let to_drop = ptr::slice_from_raw_parts_mut(drop_ptr as *mut T, drop_len);
+            ptr::drop_in_place(to_drop);
+        }
+    }
+}
+
+impl<T, A: Allocator, G: GrowthPolicy> ExactSizeIterator for Drain<'_, T, A, G> {}
+
+impl<T, A: Allocator, G: GrowthPolicy> FusedIterator for Drain<'_, T, A, G> {}
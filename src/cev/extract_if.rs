@@ -0,0 +1,99 @@
+use crate::cev::Cev;
+use crate::growth::{Doubling, GrowthPolicy};
+use allocator_api2::alloc::{Allocator, Global};
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ptr;
+
+/// An iterator which uses a closure to determine if an element should be removed.
+///
+/// This `struct` is created by [`Cev::extract_if`].
+/// See its documentation for more.
+///
+/// # Examples
+///
+/// ```
+/// use cev::{Cev, ExtractIf};
+///
+/// let mut cev = Cev::from([0, 1, 2]);
+/// let iter: ExtractIf<'_, _, _> = cev.extract_if(.., |x| *x % 2 == 0);
+/// ```
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ExtractIf<'a, T, F, A: Allocator = Global, G: GrowthPolicy = Doubling> {
+    pub(super) cev: &'a mut Cev<T, A, G>,
+    /// Pointer to the first element of the `Cev` array before the iteration.
+    pub(super) base: *mut T,
+    /// The index of the next element to visit.
+    pub(super) idx: usize,
+    /// The index past the last element of the range.
+    pub(super) end: usize,
+    /// The number of items that have been drained (removed) thus far.
+    pub(super) del: usize,
+    /// The original length of the `Cev` array prior to draining.
+    pub(super) old_len: usize,
+    /// The filter test predicate.
+    pub(super) pred: F,
+}
+
+impl<T, F, A: Allocator, G: GrowthPolicy> Iterator for ExtractIf<'_, T, F, A, G>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        unsafe {
+            while self.idx < self.end {
+                let i = self.idx;
+                let cur = self.base.add(i);
+                let drained = (self.pred)(&mut *cur);
+                // Update the index *after* the predicate is called. If the index
+                // is updated prior and the predicate panics, the element at this
+                // index would be leaked.
+                self.idx += 1;
+                if drained {
+                    self.del += 1;
+                    return Some(ptr::read(cur));
+                } else if self.del > 0 {
+                    ptr::copy_nonoverlapping(cur, self.base.add(i - self.del), 1);
+                }
+            }
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.end - self.idx))
+    }
+}
+
+impl<T, F, A: Allocator, G: GrowthPolicy> FusedIterator for ExtractIf<'_, T, F, A, G> where
+    F: FnMut(&mut T) -> bool
+{
+}
+
+impl<T, F, A: Allocator, G: GrowthPolicy> Drop for ExtractIf<'_, T, F, A, G> {
+    fn drop(&mut self) {
+        unsafe {
+            if self.del > 0 {
+                // The retained elements were compacted towards the front,
+                // close the gap by sliding everything before it back to the unvisited ones.
+                ptr::copy(self.base, self.base.add(self.del), self.idx - self.del);
+            }
+            self.cev.set_len_ptr(self.old_len - self.del);
+        }
+    }
+}
+
+impl<T: fmt::Debug, F, A: Allocator, G: GrowthPolicy> fmt::Debug for ExtractIf<'_, T, F, A, G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let peek = if self.idx < self.end {
+            Some(unsafe { &*self.base.add(self.idx) })
+        } else {
+            None
+        };
+        f.debug_struct("ExtractIf")
+            .field("peek", &peek)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,166 @@
+use crate::cev::Cev;
+use crate::growth::GrowthPolicy;
+use allocator_api2::alloc::Allocator;
+use core::{cmp, ptr, slice};
+use std::io::{self, IoSlice};
+
+impl<A: Allocator, G: GrowthPolicy> Cev<u8, A, G> {
+    /// Reads exactly `n` bytes from `reader` directly into the free space
+    /// in front of the existing content.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`read_exact`], the content of the `Cev` array is left unchanged.
+    ///
+    /// [`read_exact`]: io::Read::read_exact
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    ///
+    /// let mut cev = Cev::from(*b"body");
+    /// let mut source = &b"head:rest"[..];
+    /// cev.read_to_front(&mut source, 5).unwrap();
+    /// assert_eq!(cev, *b"head:body");
+    /// assert_eq!(source, b"rest");
+    ///
+    /// assert!(cev.read_to_front(&mut source, 5).is_err());
+    /// assert_eq!(cev, *b"head:body");
+    /// ```
+    pub fn read_to_front<R: io::Read + ?Sized>(
+        &mut self,
+        reader: &mut R,
+        n: usize,
+    ) -> io::Result<()> {
+        self.reserve(n);
+
+        unsafe {
+            let spare = self.spare_capacity_mut();
+            let dst = spare.as_mut_ptr().add(spare.len() - n) as *mut u8;
+            ptr::write_bytes(dst, 0, n);
+            reader.read_exact(slice::from_raw_parts_mut(dst, n))?;
+            self.set_len_ptr(self.len() + n);
+        }
+        Ok(())
+    }
+}
+
+impl<A: Allocator, G: GrowthPolicy> io::Read for Cev<u8, A, G> {
+    /// Reads bytes from the front of the `Cev` array and removes them,
+    /// the same as [`truncate`] does, without moving the remaining bytes.
+    ///
+    /// [`truncate`]: Cev::truncate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    /// use std::io::Read;
+    ///
+    /// let mut cev = Cev::from(*b"abcdef");
+    /// let mut buf = [0; 4];
+    /// assert_eq!(cev.read(&mut buf).unwrap(), 4);
+    /// assert_eq!(&buf, b"abcd");
+    /// assert_eq!(cev, *b"ef");
+    /// ```
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = cmp::min(buf.len(), self.len());
+        buf[..n].copy_from_slice(&self[..n]);
+        self.truncate(self.len() - n);
+        Ok(n)
+    }
+}
+
+impl<A: Allocator, G: GrowthPolicy> io::BufRead for Cev<u8, A, G> {
+    /// Returns all the bytes of the `Cev` array.
+    #[inline]
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.as_slice())
+    }
+
+    /// Removes `amt` bytes from the front of the `Cev` array, see [`io::Read::read`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    /// use std::io::BufRead;
+    ///
+    /// let mut cev = Cev::from(*b"line one\nline two\n");
+    /// let mut line = String::new();
+    /// cev.read_line(&mut line).unwrap();
+    /// assert_eq!(line, "line one\n");
+    /// assert_eq!(cev, *b"line two\n");
+    /// ```
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        let amt = cmp::min(amt, self.len());
+        self.truncate(self.len() - amt);
+    }
+}
+
+impl<A: Allocator, G: GrowthPolicy> io::Write for Cev<u8, A, G> {
+    /// Places the bytes of `buf` in front of the existing content, keeping their order.
+    ///
+    /// Each call prepends its chunk, so the chunk written last comes first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    /// use std::io::Write;
+    ///
+    /// let mut cev = Cev::new();
+    /// cev.write_all(b"trailer").unwrap();
+    /// cev.write_all(b"body:").unwrap();
+    /// cev.write_all(b"header:").unwrap();
+    /// assert_eq!(cev, *b"header:body:trailer");
+    /// ```
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        unsafe { self.append_elements(buf as _) };
+        Ok(buf.len())
+    }
+
+    /// Places the concatenation of `bufs` in front of the existing content,
+    /// the same as a single [`write`] of all the slices.
+    ///
+    /// [`write`]: io::Write::write
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    /// use std::io::{IoSlice, Write};
+    ///
+    /// let mut cev = Cev::from(*b"!");
+    /// let bufs = [IoSlice::new(b"Hello, "), IoSlice::new(b"world")];
+    /// assert_eq!(cev.write_vectored(&bufs).unwrap(), 12);
+    /// assert_eq!(cev, *b"Hello, world!");
+    /// ```
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        let len = bufs.iter().map(|buf| buf.len()).sum();
+        self.reserve(len);
+
+        for buf in bufs.iter().rev() {
+            unsafe { self.append_elements(&**buf as _) };
+        }
+        Ok(len)
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        unsafe { self.append_elements(buf as _) };
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
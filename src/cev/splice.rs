@@ -0,0 +1,142 @@
+use super::Drain;
+use crate::growth::{Doubling, GrowthPolicy};
+use crate::raw_cev::handle_reserve;
+use alloc::vec::Vec;
+use allocator_api2::alloc::{Allocator, Global};
+use core::ptr;
+
+/// A splicing iterator for `Cev<T>`.
+///
+/// This `struct` is created by [`Cev::splice`].
+/// See its documentation for more.
+///
+/// [`Cev::splice`]: crate::Cev::splice
+///
+/// # Examples
+///
+/// ```
+/// use cev::{Cev, Splice};
+///
+/// let mut cev = Cev::from([0, 1, 2]);
+/// let new = [7, 8];
+/// let iter: Splice<'_, _> = cev.splice(1.., new);
+/// ```
+#[derive(Debug)]
+pub struct Splice<'a, I: Iterator + 'a, A: Allocator + 'a = Global, G: GrowthPolicy + 'a = Doubling>
+{
+    pub(super) drain: Drain<'a, I::Item, A, G>,
+    pub(super) replace_with: I,
+}
+
+impl<I: Iterator, A: Allocator, G: GrowthPolicy> Iterator for Splice<'_, I, A, G> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.drain.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.drain.size_hint()
+    }
+}
+
+impl<I: Iterator, A: Allocator, G: GrowthPolicy> DoubleEndedIterator for Splice<'_, I, A, G> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.drain.next_back()
+    }
+}
+
+impl<I: Iterator, A: Allocator, G: GrowthPolicy> ExactSizeIterator for Splice<'_, I, A, G> {}
+
+impl<I: Iterator, A: Allocator, G: GrowthPolicy> Drop for Splice<'_, I, A, G> {
+    fn drop(&mut self) {
+        self.drain.by_ref().for_each(drop);
+        // At this point draining is done and the only remaining tasks are splicing
+        // and moving things into the final place. Growing may deallocate the memory
+        // the exhausted slice iterator points to, so replace it with an empty one.
+        self.drain.iter = [].iter();
+
+        unsafe {
+            // First fill the gap left by the drained range, right after the head.
+            if !self.drain.fill(&mut self.replace_with) {
+                return;
+            }
+
+            // There may be more elements. Use the lower bound as an estimate.
+            let (lower_bound, _upper_bound) = self.replace_with.size_hint();
+            if lower_bound > 0 {
+                self.drain.move_head(lower_bound);
+                if !self.drain.fill(&mut self.replace_with) {
+                    return;
+                }
+            }
+
+            // Collect any remaining elements.
+            // This is a zero-length vector which does not allocate if `lower_bound` was exact.
+            let mut collected = self
+                .replace_with
+                .by_ref()
+                .collect::<Vec<I::Item>>()
+                .into_iter();
+            // Now we have an exact count.
+            if collected.len() > 0 {
+                self.drain.move_head(collected.len());
+                let filled = self.drain.fill(&mut collected);
+                debug_assert!(filled);
+                debug_assert_eq!(collected.len(), 0);
+            }
+        }
+        // Let `Drain::drop` slide the head back to the tail.
+    }
+}
+
+/// Private helper methods for `Splice::drop`
+impl<T, A: Allocator, G: GrowthPolicy> Drain<'_, T, A, G> {
+    /// The range from `head_start + head_len` to the tail is filled with
+    /// elements from the `replace_with` iterator, which become part of the head.
+    /// Returns `true` if we filled the entire range. (`replace_with.next()` didn’t return `None`.)
+    unsafe fn fill<I: Iterator<Item = T>>(&mut self, replace_with: &mut I) -> bool {
+        let cev = self.cev.as_mut();
+        let raw_ptr = cev.buf.raw_ptr();
+        let tail_start = cev.capacity() - cev.len();
+
+        while self.head_start + self.head_len != tail_start {
+            if let Some(new_item) = replace_with.next() {
+                ptr::write(raw_ptr.add(self.head_start + self.head_len), new_item);
+                self.head_len += 1;
+            } else {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Makes room for inserting more elements before the tail by sliding the head
+    /// to the front, growing the allocation if the headroom is too small.
+    unsafe fn move_head(&mut self, additional: usize) {
+        let cev = self.cev.as_mut();
+
+        if self.head_start < additional {
+            // Let the allocation see the head, the gap and the tail as one live block,
+            // so that growing keeps all of them anchored at the end.
+            let tail_len = cev.len();
+            let old_cap = cev.capacity();
+            let covered = old_cap - self.head_start;
+
+            cev.set_len_ptr(covered);
+            let result = cev.buf.try_reserve(covered, additional);
+            self.head_start += cev.capacity() - old_cap;
+            cev.set_len_ptr(tail_len);
+            handle_reserve(result);
+        }
+
+        let raw_ptr = cev.buf.raw_ptr();
+        let new_head_start = self.head_start - additional;
+        ptr::copy(
+            raw_ptr.add(self.head_start),
+            raw_ptr.add(new_head_start),
+            self.head_len,
+        );
+        self.head_start = new_head_start;
+    }
+}
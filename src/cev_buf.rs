@@ -0,0 +1,368 @@
+use crate::alloc_err::TryReserveError;
+use crate::cev::Cev;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops;
+
+/// A byte buffer with headroom for prepending protocol headers.
+///
+/// `CevBuf` is backed by a `Cev<u8>` array: the free space is in front of the data,
+/// so each encapsulation layer prepends its header with the `put_*_front` methods
+/// without copying the payload. The `get_*` methods and [`advance`] read and consume
+/// bytes from the front, which is *O*(1) in the length of the buffer.
+///
+/// [`advance`]: CevBuf::advance
+///
+/// # Examples
+///
+/// ```
+/// use cev::CevBuf;
+///
+/// let mut packet = CevBuf::with_headroom(64);
+/// packet.put_slice_front(b"payload");
+/// // UDP-like header: ports and length.
+/// packet.put_u16_be_front(7 + 6);
+/// packet.put_u16_be_front(53);
+/// packet.put_u16_be_front(1024);
+/// // Link layer type.
+/// packet.put_u8_front(0x11);
+/// assert_eq!(packet.headroom(), 64 - 14);
+///
+/// assert_eq!(packet.get_u8(), Some(0x11));
+/// assert_eq!(packet.get_u16_be(), Some(1024));
+/// assert_eq!(packet.get_u16_be(), Some(53));
+/// assert_eq!(packet.get_u16_be(), Some(13));
+/// assert_eq!(packet, b"payload");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CevBuf {
+    cev: Cev<u8>,
+}
+
+macro_rules! impl_put_get {
+    ($($ty:ty, $put:ident, $get:ident, $to_bytes:ident, $from_bytes:ident, $order:literal;)*) => {$(
+        #[doc = concat!("Prepends a `", stringify!($ty), "` in ", $order, " byte order.")]
+        #[doc = ""]
+        #[doc = "# Panics"]
+        #[doc = ""]
+        #[doc = "Panics if the new capacity exceeds `isize::MAX` bytes."]
+        #[inline]
+        pub fn $put(&mut self, n: $ty) {
+            self.put_slice_front(&n.$to_bytes());
+        }
+
+        #[doc = concat!("Reads and consumes a `", stringify!($ty), "` in ", $order, " byte order")]
+        #[doc = "from the front, or returns `None` without consuming if there are not enough bytes."]
+        #[inline]
+        pub fn $get(&mut self) -> Option<$ty> {
+            self.take_front().map(<$ty>::$from_bytes)
+        }
+    )*};
+}
+
+impl CevBuf {
+    /// Consumes `cnt` bytes from the front of the buffer, adding them to the headroom.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cnt` is greater than the length of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::CevBuf;
+    ///
+    /// let mut buf = CevBuf::from(&b"hdr:data"[..]);
+    /// buf.advance(4);
+    /// assert_eq!(buf, b"data");
+    /// assert_eq!(buf.headroom(), 4);
+    /// ```
+    #[track_caller]
+    pub fn advance(&mut self, cnt: usize) {
+        let len = self.len();
+        assert!(
+            cnt <= len,
+            "cannot advance past the end of the buffer (cnt is {cnt}, len is {len})"
+        );
+        self.cev.truncate(len - cnt);
+    }
+
+    /// Extracts a mutable slice of the buffer contents.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.cev
+    }
+
+    /// Extracts a slice of the buffer contents.
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.cev
+    }
+
+    /// Total number of bytes the buffer can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.cev.capacity()
+    }
+
+    /// Removes all bytes, turning the whole capacity into headroom.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.cev.clear();
+    }
+
+    /// Copies bytes from the front of the buffer into `dst` and consumes them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is shorter than `dst`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::CevBuf;
+    ///
+    /// let mut buf = CevBuf::from(&b"abcd"[..]);
+    /// let mut dst = [0; 3];
+    /// buf.copy_to_slice(&mut dst);
+    /// assert_eq!(&dst, b"abc");
+    /// assert_eq!(buf, b"d");
+    /// ```
+    #[track_caller]
+    pub fn copy_to_slice(&mut self, dst: &mut [u8]) {
+        let cnt = dst.len();
+        assert!(
+            cnt <= self.len(),
+            "buffer too short (need {cnt}, have {})",
+            self.len()
+        );
+        dst.copy_from_slice(&self.cev[..cnt]);
+        self.advance(cnt);
+    }
+
+    /// Reads and consumes a byte from the front,
+    /// or returns `None` if the buffer is empty.
+    #[inline]
+    pub fn get_u8(&mut self) -> Option<u8> {
+        self.cev.pop()
+    }
+
+    /// Number of bytes that can be prepended without reallocating.
+    #[inline]
+    pub fn headroom(&self) -> usize {
+        self.cev.capacity() - self.cev.len()
+    }
+
+    /// Converts the buffer into its `Cev<u8>` array of bytes.
+    #[inline]
+    pub fn into_cev(self) -> Cev<u8> {
+        self.cev
+    }
+
+    /// Returns `true` if the buffer contains no bytes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cev.is_empty()
+    }
+
+    /// Number of bytes in the buffer.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cev.len()
+    }
+
+    /// Creates a new, empty `CevBuf` without headroom.
+    #[inline]
+    pub const fn new() -> CevBuf {
+        CevBuf { cev: Cev::new() }
+    }
+
+    /// Prepends the bytes of `src`, keeping their order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::CevBuf;
+    ///
+    /// let mut buf = CevBuf::from(&b"body"[..]);
+    /// buf.put_slice_front(b"GET ");
+    /// assert_eq!(buf, b"GET body");
+    /// ```
+    pub fn put_slice_front(&mut self, src: &[u8]) {
+        unsafe { self.cev.append_elements(src) };
+    }
+
+    /// Prepends a byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    #[inline]
+    pub fn put_u8_front(&mut self, n: u8) {
+        self.cev.push(n);
+    }
+
+    impl_put_get! {
+        u16, put_u16_be_front, get_u16_be, to_be_bytes, from_be_bytes, "big-endian";
+        u16, put_u16_le_front, get_u16_le, to_le_bytes, from_le_bytes, "little-endian";
+        u32, put_u32_be_front, get_u32_be, to_be_bytes, from_be_bytes, "big-endian";
+        u32, put_u32_le_front, get_u32_le, to_le_bytes, from_le_bytes, "little-endian";
+        u64, put_u64_be_front, get_u64_be, to_be_bytes, from_be_bytes, "big-endian";
+        u64, put_u64_le_front, get_u64_le, to_le_bytes, from_le_bytes, "little-endian";
+    }
+
+    /// Reserves headroom for at least `additional` more bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::CevBuf;
+    ///
+    /// let mut buf = CevBuf::from(&b"payload"[..]);
+    /// buf.reserve_headroom(20);
+    /// assert!(buf.headroom() >= 20);
+    /// ```
+    #[inline]
+    pub fn reserve_headroom(&mut self, additional: usize) {
+        self.cev.reserve(additional);
+    }
+
+    /// Tries to reserve headroom for at least `additional` more bytes.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    #[inline]
+    pub fn try_reserve_headroom(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.cev.try_reserve(additional)
+    }
+
+    /// Creates a new, empty `CevBuf` with `headroom` bytes of room for headers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    #[inline]
+    pub fn with_headroom(headroom: usize) -> CevBuf {
+        CevBuf {
+            cev: Cev::with_capacity(headroom),
+        }
+    }
+
+    fn take_front<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let bytes: [u8; N] = self.cev.get(..N)?.try_into().ok()?;
+        self.advance(N);
+        Some(bytes)
+    }
+}
+
+impl ops::Deref for CevBuf {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        &self.cev
+    }
+}
+
+impl ops::DerefMut for CevBuf {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [u8] {
+        &mut self.cev
+    }
+}
+
+impl AsRef<[u8]> for CevBuf {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl AsMut<[u8]> for CevBuf {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self
+    }
+}
+
+impl fmt::Debug for CevBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CevBuf")
+            .field("data", &self.as_slice())
+            .field("headroom", &self.headroom())
+            .finish()
+    }
+}
+
+impl PartialEq<[u8]> for CevBuf {
+    #[inline]
+    fn eq(&self, other: &[u8]) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl PartialEq<&[u8]> for CevBuf {
+    #[inline]
+    fn eq(&self, other: &&[u8]) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<const N: usize> PartialEq<[u8; N]> for CevBuf {
+    #[inline]
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<const N: usize> PartialEq<&[u8; N]> for CevBuf {
+    #[inline]
+    fn eq(&self, other: &&[u8; N]) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl From<Cev<u8>> for CevBuf {
+    /// Uses the `Cev<u8>` array as the buffer, its spare capacity becomes the headroom.
+    fn from(cev: Cev<u8>) -> CevBuf {
+        CevBuf { cev }
+    }
+}
+
+impl From<Vec<u8>> for CevBuf {
+    /// Converts the vector reusing its memory, see [`Cev::from_vec`].
+    fn from(vec: Vec<u8>) -> CevBuf {
+        CevBuf {
+            cev: Cev::from_vec(vec),
+        }
+    }
+}
+
+impl From<&[u8]> for CevBuf {
+    fn from(slice: &[u8]) -> CevBuf {
+        CevBuf {
+            cev: Cev::from(slice),
+        }
+    }
+}
+
+impl From<CevBuf> for Cev<u8> {
+    fn from(buf: CevBuf) -> Cev<u8> {
+        buf.cev
+    }
+}
+
+impl From<CevBuf> for Vec<u8> {
+    /// Converts the buffer reusing its memory, see [`Cev::into_vec`].
+    fn from(buf: CevBuf) -> Vec<u8> {
+        buf.cev.into_vec()
+    }
+}
@@ -0,0 +1,213 @@
+use crate::array_cev::CapacityError;
+use crate::front_buf::FrontBuf;
+use core::mem::MaybeUninit;
+use core::slice;
+
+/// A `Cev` array over caller-provided storage, such as a stack array, a DMA region
+/// or a buffer received from C.
+///
+/// The elements are anchored at the end of the borrowed buffer, the same as in a heap `Cev` array,
+/// so [`push`] adds to the beginning without moving the other elements.
+/// The capacity is the length of the buffer: adding to a full `CevSlice` array returns
+/// the element back in a [`CapacityError`]. The buffer is never deallocated,
+/// dropping a `CevSlice` array only drops its elements.
+///
+/// [`push`]: CevSlice::push
+///
+/// # Examples
+///
+/// ```
+/// use cev::CevSlice;
+/// use std::mem::MaybeUninit;
+///
+/// let mut storage = [MaybeUninit::uninit(); 3];
+/// let mut cev = CevSlice::new(&mut storage);
+/// cev.push(3).unwrap();
+/// cev.push(2).unwrap();
+/// cev.push(1).unwrap();
+/// assert_eq!(cev, [1, 2, 3]);
+/// assert!(cev.push(0).is_err());
+///
+/// let initialized: &mut [i32] = cev.into_slice();
+/// assert_eq!(initialized, [1, 2, 3]);
+/// ```
+pub struct CevSlice<'a, T> {
+    buf: FrontBuf<T, &'a mut [MaybeUninit<T>]>,
+}
+
+impl<'a, T> CevSlice<'a, T> {
+    /// Returns an unsafe mutable pointer to the first element.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.buf.as_mut_ptr()
+    }
+
+    /// Extracts a mutable slice of the entire `CevSlice` array.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.buf.as_mut_slice()
+    }
+
+    /// Returns an unsafe pointer to the first element.
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        self.buf.as_ptr()
+    }
+
+    /// Extracts a slice of the entire `CevSlice` array.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self.buf.as_slice()
+    }
+
+    /// Number of elements the `CevSlice` array can hold, the length of the borrowed buffer.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buf.capacity()
+    }
+
+    /// Clears the `CevSlice` array, removing all elements.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Returns the initialized elements as a slice borrowing the buffer for `'a`.
+    /// The elements are not dropped afterwards.
+    pub fn into_slice(self) -> &'a mut [T] {
+        let (slots, len) = self.buf.into_parts();
+
+        let offset = slots.len() - len;
+        unsafe { slice::from_raw_parts_mut(slots.as_mut_ptr().add(offset) as *mut T, len) }
+    }
+
+    /// Returns `true` if the `CevSlice` array contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.buf.len() == 0
+    }
+
+    /// Returns `true` if the borrowed buffer is full.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.buf.len() == self.buf.capacity()
+    }
+
+    /// `CevSlice` array length, the number of elements in the array.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Creates a new, empty `CevSlice` array over `buf`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::CevSlice;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut storage = [const { MaybeUninit::<String>::uninit() }; 4];
+    /// let cev = CevSlice::new(&mut storage);
+    /// assert!(cev.is_empty());
+    /// assert_eq!(cev.capacity(), 4);
+    /// ```
+    #[inline]
+    pub fn new(buf: &'a mut [MaybeUninit<T>]) -> Self {
+        CevSlice {
+            buf: FrontBuf::new(buf),
+        }
+    }
+
+    /// Removes the first element from the `CevSlice` array and returns it,
+    /// or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::CevSlice;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut storage = [MaybeUninit::uninit(); 2];
+    /// let mut cev = CevSlice::new(&mut storage);
+    /// cev.push(2).unwrap();
+    /// cev.push(1).unwrap();
+    /// assert_eq!(cev.pop(), Some(1));
+    /// assert_eq!(cev, [2]);
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        self.buf.pop()
+    }
+
+    /// Adds an element to the beginning of the `CevSlice` array.
+    ///
+    /// # Errors
+    ///
+    /// If the borrowed buffer is full, the element is returned in the error.
+    #[inline]
+    pub fn push(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        self.buf.push(value).map_err(CapacityError::new)
+    }
+
+    /// Number of elements that can still be added.
+    #[inline]
+    pub fn remaining_capacity(&self) -> usize {
+        self.buf.capacity() - self.buf.len()
+    }
+
+    /// Removes and returns the element at position `index` within the `CevSlice` array,
+    /// shifting all elements before it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[track_caller]
+    pub fn remove(&mut self, index: usize) -> T {
+        self.buf.remove(index)
+    }
+
+    /// Reduces the length of the `CevSlice` array to `len`,
+    /// by removing elements from the beginning of the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::Cev;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut storage = [MaybeUninit::uninit(); 4];
+    /// let mut cev = Cev::from_uninit_slice(&mut storage);
+    /// for c in ['t', 'a', 'c', 's'] {
+    ///     cev.push(c).unwrap();
+    /// }
+    /// cev.truncate(3);
+    /// assert_eq!(cev, ['c', 'a', 't']);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        self.buf.truncate(len);
+    }
+
+    /// Inserts an element at position `index` within the `CevSlice` array, shifting all
+    /// elements before it to the left.
+    ///
+    /// # Errors
+    ///
+    /// If the borrowed buffer is full, the element is returned in the error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    #[track_caller]
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>> {
+        self.buf
+            .try_insert(index, element)
+            .map_err(CapacityError::new)
+    }
+}
+
+impl_slice_traits! { [] CevSlice<'_, T> }
+
+impl_slice_eq! { [] CevSlice<'_, T>, CevSlice<'_, U> }
+impl_slice_eq! { [] CevSlice<'_, T>, [U] }
+impl_slice_eq! { [] CevSlice<'_, T>, &[U] }
+impl_slice_eq! { [const N: usize] CevSlice<'_, T>, [U; N] }
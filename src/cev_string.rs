@@ -0,0 +1,522 @@
+use crate::alloc_err::TryReserveError;
+use crate::cev::Cev;
+use alloc::string::String;
+use core::borrow::Borrow;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops;
+use core::str::{self, Utf8Error};
+
+/// A UTF-8 encoded, growable string built by adding text to its beginning.
+///
+/// `CevString` is backed by a `Cev<u8>` array, so prepending a string slice
+/// is amortized *O*(*n*) in the length of the slice, unlike `String::insert_str(0, ..)`
+/// which moves the whole string every time.
+///
+/// Formatting with [`write!`] or [`write_front!`] places the formatted text
+/// at the beginning of the string, keeping the order of its fragments.
+///
+/// [`write_front!`]: crate::write_front
+///
+/// # Examples
+///
+/// ```
+/// use cev::{write_front, CevString};
+///
+/// let mut path = CevString::from("leaf");
+/// for module in ["inner", "outer", "crate"] {
+///     write_front!(path, "{module}::").unwrap();
+/// }
+/// assert_eq!(path, "crate::outer::inner::leaf");
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Default)]
+pub struct CevString {
+    cev: Cev<u8>,
+}
+
+/// The error type for [`CevString::from_utf8`],
+/// holding the bytes that are not valid UTF-8.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FromUtf8Error {
+    bytes: Cev<u8>,
+    error: Utf8Error,
+}
+
+impl CevString {
+    /// Returns a byte slice of the contents of the `CevString`.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.cev
+    }
+
+    /// Extracts a string slice of the entire `CevString`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::CevString;
+    ///
+    /// let mut s = CevString::from("bar");
+    /// s.push_str_front("foo");
+    /// assert_eq!(s.as_str(), "foobar");
+    /// ```
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(&self.cev) }
+    }
+
+    /// Extracts a mutable string slice of the entire `CevString`.
+    #[inline]
+    pub fn as_mut_str(&mut self) -> &mut str {
+        unsafe { str::from_utf8_unchecked_mut(&mut self.cev) }
+    }
+
+    /// Returns the capacity of the `CevString` in bytes.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.cev.capacity()
+    }
+
+    /// Removes all contents of the `CevString`, keeping the capacity.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.cev.clear();
+    }
+
+    /// Converts a `Cev<u8>` array to a `CevString`, reusing its memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns the bytes back in a [`FromUtf8Error`] if they are not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::{Cev, CevString};
+    ///
+    /// let s = CevString::from_utf8(Cev::from(*b"cev")).unwrap();
+    /// assert_eq!(s, "cev");
+    ///
+    /// let err = CevString::from_utf8(Cev::from([0, 159])).unwrap_err();
+    /// assert_eq!(err.utf8_error().valid_up_to(), 1);
+    /// assert_eq!(err.into_bytes(), [0, 159]);
+    /// ```
+    pub fn from_utf8(bytes: Cev<u8>) -> Result<CevString, FromUtf8Error> {
+        match str::from_utf8(&bytes) {
+            Ok(_) => Ok(CevString { cev: bytes }),
+            Err(error) => Err(FromUtf8Error { bytes, error }),
+        }
+    }
+
+    /// Converts a `Cev<u8>` array to a `CevString` without checking the contents.
+    ///
+    /// # Safety
+    /// - `bytes` must be valid UTF-8.
+    #[inline]
+    pub unsafe fn from_utf8_unchecked(bytes: Cev<u8>) -> CevString {
+        CevString { cev: bytes }
+    }
+
+    /// Converts the `CevString` into its `Cev<u8>` array of bytes.
+    #[inline]
+    pub fn into_bytes(self) -> Cev<u8> {
+        self.cev
+    }
+
+    /// Converts the `CevString` into a `String`, reusing its memory.
+    /// The bytes are moved to the beginning of the allocation if there is spare capacity,
+    /// see [`Cev::into_vec`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::CevString;
+    ///
+    /// let mut s = CevString::with_capacity(16);
+    /// s.push_str_front("cev");
+    /// let s: String = s.into_string();
+    /// assert_eq!(s, "cev");
+    /// assert_eq!(s.capacity(), 16);
+    /// ```
+    pub fn into_string(self) -> String {
+        unsafe { String::from_utf8_unchecked(self.cev.into_vec()) }
+    }
+
+    /// Returns `true` if the `CevString` has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cev.is_empty()
+    }
+
+    /// Returns the length of the `CevString` in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cev.len()
+    }
+
+    /// Creates a new, empty `CevString`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::CevString;
+    ///
+    /// let s = CevString::new();
+    /// assert_eq!(s, "");
+    /// assert_eq!(s.capacity(), 0);
+    /// ```
+    #[inline]
+    pub const fn new() -> CevString {
+        CevString { cev: Cev::new() }
+    }
+
+    /// Removes the first character from the `CevString` and returns it,
+    /// or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::CevString;
+    ///
+    /// let mut s = CevString::from("ñu");
+    /// assert_eq!(s.pop_front(), Some('ñ'));
+    /// assert_eq!(s.pop_front(), Some('u'));
+    /// assert_eq!(s.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<char> {
+        let ch = self.chars().next()?;
+        self.cev.truncate(self.len() - ch.len_utf8());
+        Some(ch)
+    }
+
+    /// Adds a character to the beginning of the `CevString`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::CevString;
+    ///
+    /// let mut s = CevString::from("23");
+    /// s.push_front('1');
+    /// s.push_front('№');
+    /// assert_eq!(s, "№123");
+    /// ```
+    pub fn push_front(&mut self, ch: char) {
+        self.push_str_front(ch.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Adds a string slice to the beginning of the `CevString`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::CevString;
+    ///
+    /// let mut s = CevString::from("name");
+    /// s.push_str_front("qualified::");
+    /// assert_eq!(s, "qualified::name");
+    /// ```
+    pub fn push_str_front(&mut self, string: &str) {
+        unsafe { self.cev.append_elements(string.as_bytes()) };
+    }
+
+    /// Reserves capacity for at least `additional` more bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.cev.reserve(additional);
+    }
+
+    /// Shortens the `CevString` to `new_len` bytes, by removing characters from its beginning.
+    /// Does nothing if `new_len` is not less than the current length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the removed part does not end on a `char` boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::CevString;
+    ///
+    /// let mut s = CevString::from("prefix: message");
+    /// s.truncate_front(7);
+    /// assert_eq!(s, "message");
+    /// ```
+    pub fn truncate_front(&mut self, new_len: usize) {
+        if new_len < self.len() {
+            assert!(self.is_char_boundary(self.len() - new_len));
+            self.cev.truncate(new_len);
+        }
+    }
+
+    /// Tries to reserve capacity for at least `additional` more bytes.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.cev.try_reserve(additional)
+    }
+
+    /// Creates a new, empty `CevString` with at least the specified capacity in bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> CevString {
+        CevString {
+            cev: Cev::with_capacity(capacity),
+        }
+    }
+
+    /// Writes formatted text to the beginning of the `CevString`, keeping the order
+    /// of the formatted fragments. This is the method behind [`write_front!`].
+    ///
+    /// The fragments are prepended byte by byte in reverse, then the written bytes are
+    /// reversed once, which leaves each fragment readable and in order.
+    /// If formatting fails or panics, the fragments written so far are kept.
+    ///
+    /// [`write_front!`]: crate::write_front
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a formatting trait implementation returns an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::CevString;
+    ///
+    /// let mut s = CevString::from("!");
+    /// s.write_fmt_front(format_args!("{}, {}", "Hello", "world")).unwrap();
+    /// assert_eq!(s, "Hello, world!");
+    /// ```
+    pub fn write_fmt_front(&mut self, args: fmt::Arguments<'_>) -> fmt::Result {
+        struct ReversedFront<'a> {
+            cev: &'a mut Cev<u8>,
+            len: usize,
+        }
+
+        impl fmt::Write for ReversedFront<'_> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.cev.extend(s.bytes());
+                Ok(())
+            }
+        }
+
+        impl Drop for ReversedFront<'_> {
+            fn drop(&mut self) {
+                let written = self.cev.len() - self.len;
+                self.cev[..written].reverse();
+            }
+        }
+
+        if let Some(s) = args.as_str() {
+            self.push_str_front(s);
+            return Ok(());
+        }
+
+        let len = self.len();
+        fmt::write(
+            &mut ReversedFront {
+                cev: &mut self.cev,
+                len,
+            },
+            args,
+        )
+    }
+}
+
+impl FromUtf8Error {
+    /// Returns the bytes that were attempted to convert to a `CevString`.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the bytes that were attempted to convert to a `CevString`.
+    pub fn into_bytes(self) -> Cev<u8> {
+        self.bytes
+    }
+
+    /// Details about the conversion failure.
+    pub fn utf8_error(&self) -> Utf8Error {
+        self.error
+    }
+}
+
+impl fmt::Display for FromUtf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromUtf8Error {}
+
+/// Writes formatted text to the beginning of a [`CevString`].
+///
+/// Takes the same arguments as [`write!`] and returns a [`fmt::Result`](core::fmt::Result).
+///
+/// # Examples
+///
+/// ```
+/// use cev::{write_front, CevString};
+///
+/// let mut s = CevString::from("world");
+/// write_front!(s, "{}, ", "Hello").unwrap();
+/// assert_eq!(s, "Hello, world");
+/// ```
+#[macro_export]
+macro_rules! write_front {
+    ($dst:expr, $($arg:tt)*) => {
+        $dst.write_fmt_front(::core::format_args!($($arg)*))
+    };
+}
+
+impl fmt::Write for CevString {
+    /// Adds the string slice to the beginning of the `CevString`.
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str_front(s);
+        Ok(())
+    }
+
+    /// Adds the character to the beginning of the `CevString`.
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.push_front(c);
+        Ok(())
+    }
+
+    /// Writes formatted text to the beginning of the `CevString`,
+    /// see [`CevString::write_fmt_front`].
+    #[inline]
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> fmt::Result {
+        self.write_fmt_front(args)
+    }
+}
+
+impl ops::Deref for CevString {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl ops::DerefMut for CevString {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl AsRef<str> for CevString {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl AsRef<[u8]> for CevString {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Borrow<str> for CevString {
+    fn borrow(&self) -> &str {
+        self
+    }
+}
+
+impl fmt::Display for CevString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Debug for CevString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Hash for CevString {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(self.as_str(), state)
+    }
+}
+
+impl PartialEq<str> for CevString {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for CevString {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for CevString {
+    #[inline]
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<CevString> for str {
+    #[inline]
+    fn eq(&self, other: &CevString) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<CevString> for &str {
+    #[inline]
+    fn eq(&self, other: &CevString) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl From<&str> for CevString {
+    fn from(s: &str) -> CevString {
+        CevString {
+            cev: Cev::from(s.as_bytes()),
+        }
+    }
+}
+
+impl From<String> for CevString {
+    /// Converts a `String` reusing its memory, see [`Cev::from_vec`].
+    fn from(s: String) -> CevString {
+        CevString {
+            cev: Cev::from_vec(s.into_bytes()),
+        }
+    }
+}
+
+impl From<CevString> for String {
+    /// Converts a `CevString` reusing its memory, see [`CevString::into_string`].
+    fn from(s: CevString) -> String {
+        s.into_string()
+    }
+}
+
+impl From<CevString> for Cev<u8> {
+    fn from(s: CevString) -> Cev<u8> {
+        s.into_bytes()
+    }
+}
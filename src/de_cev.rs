@@ -0,0 +1,662 @@
+use crate::alloc_err::{TryReserveError, TryReserveErrorKind};
+use crate::cev::Cev;
+use crate::raw_cev::{handle_reserve, RawCev};
+use alloc::vec::Vec;
+use core::cmp;
+use core::mem::ManuallyDrop;
+use core::ptr;
+use core::slice;
+
+/// A contiguous array of data allocated on the heap that grows at both ends.
+///
+/// `DeCev` keeps independent headroom before the first and after the last element,
+/// so both [`push_front`] and [`push_back`] are amortized *O*(1),
+/// while the elements always form a single slice.
+/// When one end runs out of headroom, the free space is split evenly between both ends again,
+/// by moving the elements within the allocation or into a larger one.
+///
+/// [`push_front`]: DeCev::push_front
+/// [`push_back`]: DeCev::push_back
+///
+/// # Examples
+///
+/// ```
+/// use cev::DeCev;
+///
+/// let mut msg = DeCev::new();
+/// msg.extend_from_slice(b"payload");
+/// msg.prepend_slice(b"HDR:");
+/// msg.extend_from_slice(b":CRC");
+/// assert_eq!(&msg[..], b"HDR:payload:CRC");
+/// ```
+pub struct DeCev<T> {
+    buf: RawCev<T>,
+    head: usize,
+    len: usize,
+}
+
+impl<T> DeCev<T> {
+    /// Returns a constant unsafe pointer to the first element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::DeCev;
+    ///
+    /// let decev = DeCev::from(vec![1, 2]);
+    /// assert_eq!(unsafe { *decev.as_ptr() }, 1);
+    /// ```
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr()
+    }
+
+    /// Returns an unsafe mutable pointer to the first element.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr()
+    }
+
+    /// Extracts a slice of the entire `DeCev` array.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.ptr(), self.len) }
+    }
+
+    /// Extracts a mutable slice of the entire `DeCev` array.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.ptr(), self.len) }
+    }
+
+    /// Returns the number of elements that can be added after the last element
+    /// without moving the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::DeCev;
+    ///
+    /// let decev: DeCev<u8> = DeCev::with_headroom(2, 5);
+    /// assert_eq!(decev.back_headroom(), 5);
+    /// ```
+    #[inline]
+    pub fn back_headroom(&self) -> usize {
+        self.capacity() - self.head - self.len
+    }
+
+    /// Total number of elements the `DeCev` array can hold without reallocating,
+    /// counting the headroom at both ends.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buf.capacity()
+    }
+
+    /// Clears the `DeCev` array, removing all elements.
+    /// The headroom is split evenly between both ends again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::DeCev;
+    ///
+    /// let mut decev = DeCev::from(vec![1, 2, 3, 4]);
+    /// decev.clear();
+    /// assert!(decev.is_empty());
+    /// assert_eq!(decev.front_headroom(), 2);
+    /// assert_eq!(decev.back_headroom(), 2);
+    /// ```
+    pub fn clear(&mut self) {
+        let elems: *mut [T] = self.as_mut_slice();
+        self.len = 0;
+        if !RawCev::<T>::IS_ZST {
+            self.head = self.capacity() / 2;
+        }
+        unsafe { ptr::drop_in_place(elems) };
+    }
+
+    /// Returns the number of elements that can be added before the first element
+    /// without moving the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::DeCev;
+    ///
+    /// let decev: DeCev<u8> = DeCev::with_headroom(2, 5);
+    /// assert_eq!(decev.front_headroom(), 2);
+    /// ```
+    #[inline]
+    pub fn front_headroom(&self) -> usize {
+        if RawCev::<T>::IS_ZST {
+            usize::MAX - self.len
+        } else {
+            self.head
+        }
+    }
+
+    /// Returns `true` if the `DeCev` array contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of elements in the `DeCev` array.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Creates a new, empty `DeCev<T>`.
+    /// No memory is allocated until elements are pushed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::DeCev;
+    ///
+    /// let decev: DeCev<i32> = DeCev::new();
+    /// assert_eq!(decev.capacity(), 0);
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        DeCev {
+            buf: RawCev::NEW,
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Removes the last element and returns it, or `None` if the `DeCev` array is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::DeCev;
+    ///
+    /// let mut decev = DeCev::from(vec![1, 2]);
+    /// assert_eq!(decev.pop_back(), Some(2));
+    /// assert_eq!(decev.pop_back(), Some(1));
+    /// assert_eq!(decev.pop_back(), None);
+    /// ```
+    #[inline]
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            self.len -= 1;
+            unsafe { Some(ptr::read(self.ptr().add(self.len))) }
+        }
+    }
+
+    /// Removes the first element and returns it, or `None` if the `DeCev` array is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::DeCev;
+    ///
+    /// let mut decev = DeCev::from(vec![1, 2]);
+    /// assert_eq!(decev.pop_front(), Some(1));
+    /// assert_eq!(decev.pop_front(), Some(2));
+    /// assert_eq!(decev.pop_front(), None);
+    /// ```
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            let value = unsafe { ptr::read(self.ptr()) };
+            self.len -= 1;
+            if !RawCev::<T>::IS_ZST {
+                self.head += 1;
+            }
+            Some(value)
+        }
+    }
+
+    /// Appends an element after the last element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::DeCev;
+    ///
+    /// let mut decev = DeCev::new();
+    /// decev.push_back(1);
+    /// decev.push_back(2);
+    /// assert_eq!(decev, [1, 2]);
+    /// ```
+    #[inline]
+    pub fn push_back(&mut self, value: T) {
+        if self.back_headroom() == 0 {
+            self.grow(0, 1);
+        }
+        unsafe { ptr::write(self.ptr().add(self.len), value) };
+        self.len += 1;
+    }
+
+    /// Adds an element before the first element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::DeCev;
+    ///
+    /// let mut decev = DeCev::new();
+    /// decev.push_front(1);
+    /// decev.push_front(2);
+    /// assert_eq!(decev, [2, 1]);
+    /// ```
+    #[inline]
+    pub fn push_front(&mut self, value: T) {
+        if self.front_headroom() == 0 {
+            self.grow(1, 0);
+        }
+        if !RawCev::<T>::IS_ZST {
+            self.head -= 1;
+        }
+        unsafe { ptr::write(self.ptr(), value) };
+        self.len += 1;
+    }
+
+    /// Reserves headroom for at least `additional` more elements after the last element.
+    /// The headroom before the first element may shrink when the elements are moved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::DeCev;
+    ///
+    /// let mut decev = DeCev::from(vec![1]);
+    /// decev.reserve_back(10);
+    /// assert!(decev.back_headroom() >= 10);
+    /// ```
+    pub fn reserve_back(&mut self, additional: usize) {
+        handle_reserve(self.try_reserve_back(additional));
+    }
+
+    /// Reserves headroom for at least `additional` more elements before the first element.
+    /// The headroom after the last element may shrink when the elements are moved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::DeCev;
+    ///
+    /// let mut decev = DeCev::from(vec![1]);
+    /// decev.reserve_front(10);
+    /// assert!(decev.front_headroom() >= 10);
+    /// ```
+    pub fn reserve_front(&mut self, additional: usize) {
+        handle_reserve(self.try_reserve_front(additional));
+    }
+
+    /// Tries to reserve headroom for at least `additional` more elements after the last element.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::DeCev;
+    ///
+    /// let mut decev: DeCev<u32> = DeCev::new();
+    /// assert!(decev.try_reserve_back(10).is_ok());
+    /// assert!(decev.try_reserve_back(usize::MAX).is_err());
+    /// ```
+    pub fn try_reserve_back(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        if self.back_headroom() >= additional {
+            Ok(())
+        } else {
+            self.make_room(0, additional)
+        }
+    }
+
+    /// Tries to reserve headroom for at least `additional` more elements before the first element.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::DeCev;
+    ///
+    /// let mut decev: DeCev<u32> = DeCev::new();
+    /// assert!(decev.try_reserve_front(10).is_ok());
+    /// assert!(decev.try_reserve_front(usize::MAX).is_err());
+    /// ```
+    pub fn try_reserve_front(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        if self.front_headroom() >= additional {
+            Ok(())
+        } else {
+            self.make_room(additional, 0)
+        }
+    }
+
+    /// Creates a new `DeCev` array with the given capacity,
+    /// split evenly between the headroom at both ends.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::DeCev;
+    ///
+    /// let decev: DeCev<u8> = DeCev::with_capacity(9);
+    /// assert_eq!(decev.front_headroom(), 4);
+    /// assert_eq!(decev.back_headroom(), 5);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_headroom(capacity / 2, capacity - capacity / 2)
+    }
+
+    /// Creates a new `DeCev` array with `front` elements of headroom before
+    /// and `back` elements of headroom after the elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::DeCev;
+    ///
+    /// let mut decev = DeCev::with_headroom(1, 2);
+    /// assert_eq!(decev.capacity(), 3);
+    /// decev.push_front(0);
+    /// decev.push_back(1);
+    /// decev.push_back(2);
+    /// assert_eq!(decev.capacity(), 3);
+    /// ```
+    pub fn with_headroom(front: usize, back: usize) -> Self {
+        let capacity = front.checked_add(back).expect("capacity overflow");
+
+        DeCev {
+            buf: RawCev::with_capacity(capacity),
+            head: if RawCev::<T>::IS_ZST { 0 } else { front },
+            len: 0,
+        }
+    }
+
+    /// Grows the allocation for the cold path of `push_front` and `push_back`.
+    #[cold]
+    #[inline(never)]
+    fn grow(&mut self, front: usize, back: usize) {
+        handle_reserve(self.make_room(front, back));
+    }
+
+    /// Ensures at least `front` elements of headroom before and `back` elements after
+    /// the elements. The remaining free space is split evenly between both ends.
+    fn make_room(&mut self, front: usize, back: usize) -> Result<(), TryReserveError> {
+        if RawCev::<T>::IS_ZST {
+            return Err(TryReserveErrorKind::CapacityOverflow.into());
+        }
+
+        let required = front
+            .checked_add(back)
+            .and_then(|additional| additional.checked_add(self.len))
+            .ok_or(TryReserveErrorKind::CapacityOverflow)?;
+        let cap = self.capacity();
+
+        // Moving the elements within the allocation is amortized as long as
+        // it leaves a headroom proportional to the length, otherwise the allocation grows.
+        // `RawCev` is told that no elements are at its end, so they keep their offset
+        // from the start of the allocation and are only moved once, below.
+        if required > cap || cap - required < self.len / 2 {
+            self.buf.try_reserve(0, cmp::max(required, cap + 1))?;
+        }
+
+        let new_head = front + (self.capacity() - required) / 2;
+        unsafe { ptr::copy(self.ptr(), self.buf.raw_ptr().add(new_head), self.len) };
+        self.head = new_head;
+        Ok(())
+    }
+
+    #[inline]
+    fn ptr(&self) -> *mut T {
+        unsafe { self.buf.raw_ptr().add(self.head) }
+    }
+}
+
+impl<T: Clone> DeCev<T> {
+    /// Clones and appends all elements of the slice after the last element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::DeCev;
+    ///
+    /// let mut decev = DeCev::from(vec![1]);
+    /// decev.extend_from_slice(&[2, 3]);
+    /// assert_eq!(decev, [1, 2, 3]);
+    /// ```
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        self.reserve_back(other.len());
+        for elem in other {
+            unsafe { ptr::write(self.ptr().add(self.len), elem.clone()) };
+            self.len += 1;
+        }
+    }
+
+    /// Clones and places all elements of the slice before the first element,
+    /// keeping their order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::DeCev;
+    ///
+    /// let mut decev = DeCev::from(vec![3]);
+    /// decev.prepend_slice(&[1, 2]);
+    /// assert_eq!(decev, [1, 2, 3]);
+    /// ```
+    pub fn prepend_slice(&mut self, other: &[T]) {
+        self.reserve_front(other.len());
+        for elem in other.iter().rev() {
+            let value = elem.clone();
+            if !RawCev::<T>::IS_ZST {
+                self.head -= 1;
+            }
+            unsafe { ptr::write(self.ptr(), value) };
+            self.len += 1;
+        }
+    }
+}
+
+impl<T> Drop for DeCev<T> {
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
+
+impl_slice_traits! { [] DeCev<T> }
+
+impl_slice_eq! { [] DeCev<T>, DeCev<U> }
+impl_slice_eq! { [] DeCev<T>, [U] }
+impl_slice_eq! { [] DeCev<T>, &[U] }
+impl_slice_eq! { [const N: usize] DeCev<T>, [U; N] }
+
+impl<T> Default for DeCev<T> {
+    fn default() -> DeCev<T> {
+        DeCev::new()
+    }
+}
+
+impl<T: Clone> Clone for DeCev<T> {
+    fn clone(&self) -> Self {
+        let mut decev = DeCev::with_headroom(0, self.len);
+        decev.extend_from_slice(self);
+        decev
+    }
+}
+
+impl<T> Extend<T> for DeCev<T> {
+    /// Appends every item of the iterator after the last element.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        self.reserve_back(lower);
+
+        for elem in iter {
+            self.push_back(elem);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for DeCev<T> {
+    /// Appends a copy of every item of the iterator after the last element.
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T> FromIterator<T> for DeCev<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> DeCev<T> {
+        let mut decev = DeCev::new();
+        decev.extend(iter);
+        decev
+    }
+}
+
+impl<T> From<Cev<T>> for DeCev<T> {
+    /// Converts a `Cev` array without copying,
+    /// its free space becomes the headroom before the first element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::{Cev, DeCev};
+    ///
+    /// let mut cev = Cev::with_capacity(4);
+    /// cev.push(1);
+    /// let decev = DeCev::from(cev);
+    /// assert_eq!(decev, [1]);
+    /// assert_eq!(decev.front_headroom(), 3);
+    /// assert_eq!(decev.back_headroom(), 0);
+    /// ```
+    fn from(cev: Cev<T>) -> DeCev<T> {
+        let cev = ManuallyDrop::new(cev);
+        let (raw_ptr, len, cap) = (cev.raw_ptr() as *mut T, cev.len(), cev.capacity());
+
+        DeCev {
+            buf: unsafe { RawCev::from_raw_parts_ptr(raw_ptr, cap) },
+            head: if RawCev::<T>::IS_ZST { 0 } else { cap - len },
+            len,
+        }
+    }
+}
+
+impl<T> From<DeCev<T>> for Cev<T> {
+    /// Converts a `DeCev` array reusing its memory.
+    /// The elements are moved to the end of the allocation if there is headroom after them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::{Cev, DeCev};
+    ///
+    /// let mut decev = DeCev::with_headroom(2, 2);
+    /// decev.push_back(1);
+    /// let mut cev = Cev::from(decev);
+    /// cev.push(0);
+    /// assert_eq!(cev, [0, 1]);
+    /// assert_eq!(cev.capacity(), 4);
+    /// ```
+    fn from(decev: DeCev<T>) -> Cev<T> {
+        let decev = ManuallyDrop::new(decev);
+        let (raw_ptr, len, cap) = (decev.buf.raw_ptr(), decev.len, decev.capacity());
+
+        unsafe {
+            let mov_ptr = if cap == 0 || RawCev::<T>::IS_ZST {
+                raw_ptr
+            } else {
+                let mov_ptr = raw_ptr.add(cap - len);
+                ptr::copy(decev.ptr(), mov_ptr, len);
+                mov_ptr
+            };
+            Cev::from_raw_parts(mov_ptr, raw_ptr, len, cap)
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for DeCev<T> {
+    /// Converts a `std` vector without copying,
+    /// its spare capacity becomes the headroom after the last element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::DeCev;
+    ///
+    /// let mut vec = Vec::with_capacity(4);
+    /// vec.push(1);
+    /// let decev = DeCev::from(vec);
+    /// assert_eq!(decev, [1]);
+    /// assert_eq!(decev.front_headroom(), 0);
+    /// assert_eq!(decev.back_headroom(), 3);
+    /// ```
+    fn from(vec: Vec<T>) -> DeCev<T> {
+        let mut vec = ManuallyDrop::new(vec);
+        let (raw_ptr, len, cap) = (vec.as_mut_ptr(), vec.len(), vec.capacity());
+
+        DeCev {
+            buf: unsafe { RawCev::from_raw_parts_ptr(raw_ptr, cap) },
+            head: 0,
+            len,
+        }
+    }
+}
+
+impl<T> From<DeCev<T>> for Vec<T> {
+    /// Converts a `DeCev` array reusing its memory.
+    /// The elements are moved to the beginning of the allocation if there is headroom before them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::DeCev;
+    ///
+    /// let mut decev = DeCev::with_headroom(2, 2);
+    /// decev.push_front(1);
+    /// let mut vec = Vec::from(decev);
+    /// vec.push(2);
+    /// assert_eq!(vec, [1, 2]);
+    /// assert_eq!(vec.capacity(), 4);
+    /// ```
+    fn from(decev: DeCev<T>) -> Vec<T> {
+        let decev = ManuallyDrop::new(decev);
+        let (raw_ptr, len, cap) = (decev.buf.raw_ptr(), decev.len, decev.capacity());
+
+        unsafe {
+            if decev.head != 0 {
+                ptr::copy(decev.ptr(), raw_ptr, len);
+            }
+            Vec::from_raw_parts(raw_ptr, len, cap)
+        }
+    }
+}
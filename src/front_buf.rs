@@ -0,0 +1,184 @@
+use core::marker::PhantomData;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ptr;
+use core::slice;
+
+/// Storage for the slots of a [`FrontBuf`], an inline array or a borrowed slice.
+pub(crate) trait Slots<T>: AsRef<[MaybeUninit<T>]> + AsMut<[MaybeUninit<T>]> {}
+
+impl<T, S: AsRef<[MaybeUninit<T>]> + AsMut<[MaybeUninit<T>]>> Slots<T> for S {}
+
+/// Elements anchored at the end of a fixed number of slots, the same as in a heap `Cev` array.
+///
+/// The element at index `i` lives in the slot `capacity - len + i`,
+/// so pushing to the beginning never moves the other elements.
+/// This is the shared core of [`ArrayCev`], [`CevSlice`] and the inline [`SmallCev`] storage,
+/// which only differ in where the slots come from.
+///
+/// [`ArrayCev`]: crate::ArrayCev
+/// [`CevSlice`]: crate::CevSlice
+/// [`SmallCev`]: crate::SmallCev
+pub(crate) struct FrontBuf<T, S: Slots<T>> {
+    slots: S,
+    len: usize,
+    marker: PhantomData<T>,
+}
+
+impl<T, S: Slots<T>> FrontBuf<T, S> {
+    #[inline]
+    pub(crate) const fn new(slots: S) -> Self {
+        FrontBuf {
+            slots,
+            len: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// The last `len` slots must be initialized.
+    #[inline]
+    pub(crate) unsafe fn from_parts(slots: S, len: usize) -> Self {
+        FrontBuf {
+            slots,
+            len,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the slots and the length without dropping the elements.
+    #[inline]
+    pub(crate) fn into_parts(self) -> (S, usize) {
+        let this = ManuallyDrop::new(self);
+        (unsafe { ptr::read(&this.slots) }, this.len)
+    }
+
+    #[inline]
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut T {
+        let offset = self.capacity() - self.len;
+        unsafe { self.slots.as_mut().as_mut_ptr().add(offset) as *mut T }
+    }
+
+    #[inline]
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) }
+    }
+
+    #[inline]
+    pub(crate) fn as_ptr(&self) -> *const T {
+        let offset = self.capacity() - self.len;
+        unsafe { self.slots.as_ref().as_ptr().add(offset) as *const T }
+    }
+
+    #[inline]
+    pub(crate) fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.as_ptr(), self.len) }
+    }
+
+    #[inline]
+    pub(crate) fn capacity(&self) -> usize {
+        self.slots.as_ref().len()
+    }
+
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub(crate) fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            let value = unsafe { ptr::read(self.as_ptr()) };
+            self.len -= 1;
+            Some(value)
+        }
+    }
+
+    /// Adds an element to the beginning, or returns it back if all slots are taken.
+    #[inline]
+    pub(crate) fn push(&mut self, value: T) -> Result<(), T> {
+        if self.len == self.capacity() {
+            return Err(value);
+        }
+
+        unsafe { self.as_mut_ptr().sub(1).write(value) };
+        self.len += 1;
+        Ok(())
+    }
+
+    #[track_caller]
+    pub(crate) fn remove(&mut self, index: usize) -> T {
+        #[cold]
+        #[inline(never)]
+        #[track_caller]
+        fn assert_failed(index: usize, len: usize) -> ! {
+            panic!("removal index (is {index}) should be < len (is {len})");
+        }
+
+        if index >= self.len {
+            assert_failed(index, self.len);
+        }
+
+        unsafe {
+            let p = self.as_mut_ptr();
+            let ret = ptr::read(p.add(index));
+            ptr::copy(p, p.add(1), index);
+            self.len -= 1;
+            ret
+        }
+    }
+
+    /// Sets the length without dropping or initializing any element.
+    #[inline]
+    pub(crate) unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= self.capacity());
+
+        self.len = new_len;
+    }
+
+    pub(crate) fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+
+        let remaining_len = self.len - len;
+        unsafe {
+            let s = ptr::slice_from_raw_parts_mut(self.as_mut_ptr(), remaining_len);
+            self.len = len;
+            ptr::drop_in_place(s);
+        }
+    }
+
+    /// Inserts an element at `index`, or returns it back if all slots are taken.
+    /// Panics if `index > len`, even if all slots are taken.
+    #[track_caller]
+    pub(crate) fn try_insert(&mut self, index: usize, element: T) -> Result<(), T> {
+        #[cold]
+        #[inline(never)]
+        #[track_caller]
+        fn assert_failed(index: usize, len: usize) -> ! {
+            panic!("insertion index (is {index}) should be <= len (is {len})");
+        }
+
+        if index > self.len {
+            assert_failed(index, self.len);
+        }
+        if self.len == self.capacity() {
+            return Err(element);
+        }
+
+        unsafe {
+            let p = self.as_mut_ptr();
+            ptr::copy(p, p.sub(1), index);
+            ptr::write(p.sub(1).add(index), element);
+        }
+        self.len += 1;
+        Ok(())
+    }
+}
+
+impl<T, S: Slots<T>> Drop for FrontBuf<T, S> {
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
@@ -0,0 +1,192 @@
+use core::cmp;
+
+/// A strategy that decides how much a `Cev` array grows when it runs out of capacity.
+///
+/// The policy is used by the amortized growth paths: [`push`], [`insert`], [`append`],
+/// [`reserve`] and the methods built on them.
+/// [`reserve_exact`] and [`try_reserve_exact`] bypass it.
+///
+/// Closures `Fn(usize, usize, usize) -> usize` implement this trait as well,
+/// they receive the same arguments as [`GrowthPolicy::grow`].
+///
+/// [`push`]: crate::Cev::push
+/// [`insert`]: crate::Cev::insert
+/// [`append`]: crate::Cev::append
+/// [`reserve`]: crate::Cev::reserve
+/// [`reserve_exact`]: crate::Cev::reserve_exact
+/// [`try_reserve_exact`]: crate::Cev::try_reserve_exact
+///
+/// # Examples
+///
+/// ```
+/// use cev::{Cev, GrowthPolicy};
+///
+/// struct Triple;
+///
+/// impl GrowthPolicy for Triple {
+///     fn grow(&self, cap: usize, required: usize, _elem_size: usize) -> usize {
+///         cap.saturating_mul(3).max(required)
+///     }
+///
+///     fn min_non_zero_cap(&self, _elem_size: usize) -> usize {
+///         16
+///     }
+/// }
+///
+/// let mut cev = Cev::new().with_growth_policy(Triple);
+/// cev.push(1);
+/// assert_eq!(cev.capacity(), 16);
+/// cev.extend(0..16);
+/// assert_eq!(cev.capacity(), 48);
+///
+/// let mut cev = Cev::new().with_growth_policy(|cap: usize, required: usize, _: usize| cap + required);
+/// cev.push(1);
+/// assert_eq!(cev.capacity(), 4);
+/// ```
+pub trait GrowthPolicy {
+    /// Returns the new capacity of an array of `cap` elements of `elem_size` bytes,
+    /// which has to make room for at least `required` elements.
+    ///
+    /// A result below `required` or below [`min_non_zero_cap`] is raised to it.
+    ///
+    /// [`min_non_zero_cap`]: GrowthPolicy::min_non_zero_cap
+    fn grow(&self, cap: usize, required: usize, elem_size: usize) -> usize;
+
+    /// Returns the smallest capacity allocated for elements of `elem_size` bytes.
+    ///
+    /// Defaults to 8 for one byte elements, 4 for elements up to 1 KiB and 1 for larger ones.
+    fn min_non_zero_cap(&self, elem_size: usize) -> usize {
+        if elem_size == 1 {
+            8
+        } else if elem_size <= 1024 {
+            4
+        } else {
+            1
+        }
+    }
+}
+
+impl<F> GrowthPolicy for F
+where
+    F: Fn(usize, usize, usize) -> usize,
+{
+    #[inline]
+    fn grow(&self, cap: usize, required: usize, elem_size: usize) -> usize {
+        self(cap, required, elem_size)
+    }
+}
+
+/// Returns the capacity the amortized growth paths allocate to make room for `required`
+/// elements: the choice of `growth`, raised to `required` and to the minimum non-zero capacity.
+#[inline]
+pub(crate) fn amortized_capacity<G: GrowthPolicy>(
+    growth: &G,
+    cap: usize,
+    required: usize,
+    elem_size: usize,
+) -> usize {
+    let new_cap = cmp::max(growth.grow(cap, required, elem_size), required);
+    cmp::max(growth.min_non_zero_cap(elem_size), new_cap)
+}
+
+/// Doubles the capacity, the default growth policy.
+///
+/// # Examples
+///
+/// ```
+/// use cev::Cev;
+///
+/// let mut cev = Cev::from([1, 2, 3, 4, 5]);
+/// cev.push(0);
+/// assert_eq!(cev.capacity(), 10);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Doubling;
+
+impl GrowthPolicy for Doubling {
+    #[inline]
+    fn grow(&self, cap: usize, required: usize, _elem_size: usize) -> usize {
+        cap.saturating_mul(2).max(required)
+    }
+}
+
+/// Grows the capacity by half of it.
+///
+/// # Examples
+///
+/// ```
+/// use cev::{Cev, OneAndHalf};
+///
+/// let mut cev = Cev::from([1, 2, 3, 4, 5, 6]).with_growth_policy(OneAndHalf);
+/// cev.push(0);
+/// assert_eq!(cev.capacity(), 9);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct OneAndHalf;
+
+impl GrowthPolicy for OneAndHalf {
+    #[inline]
+    fn grow(&self, cap: usize, required: usize, _elem_size: usize) -> usize {
+        cap.saturating_add(cap / 2).max(required)
+    }
+}
+
+/// Grows the capacity by a fixed number of elements.
+///
+/// # Examples
+///
+/// ```
+/// use cev::{Cev, FixedIncrement};
+///
+/// let mut cev = Cev::from([1, 2, 3]).with_growth_policy(FixedIncrement(100));
+/// cev.push(0);
+/// assert_eq!(cev.capacity(), 103);
+///
+/// cev.growth_policy_mut().0 = 10;
+/// cev.extend(0..100);
+/// assert_eq!(cev.capacity(), 113);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FixedIncrement(pub usize);
+
+impl GrowthPolicy for FixedIncrement {
+    #[inline]
+    fn grow(&self, cap: usize, required: usize, _elem_size: usize) -> usize {
+        cap.saturating_add(self.0).max(required)
+    }
+}
+
+/// Doubles the capacity and rounds the size of the allocation up to a multiple of the
+/// page size in bytes. The default page size is 4096 bytes.
+///
+/// # Examples
+///
+/// ```
+/// use cev::{Cev, PageRounded};
+///
+/// let mut cev: Cev<u32, _, _> = Cev::new().with_growth_policy(PageRounded::default());
+/// cev.push(1);
+/// assert_eq!(cev.capacity(), 1024);
+/// cev.extend(0..1024);
+/// assert_eq!(cev.capacity(), 2048);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PageRounded(pub usize);
+
+impl Default for PageRounded {
+    fn default() -> Self {
+        PageRounded(4096)
+    }
+}
+
+impl GrowthPolicy for PageRounded {
+    fn grow(&self, cap: usize, required: usize, elem_size: usize) -> usize {
+        let cap = cap.saturating_mul(2).max(required);
+        if elem_size == 0 || self.0 == 0 {
+            return cap;
+        }
+
+        let size = cap.saturating_mul(elem_size);
+        size.div_ceil(self.0).saturating_mul(self.0) / elem_size
+    }
+}
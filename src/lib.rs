@@ -1,3 +1,4 @@
+#![no_std]
 #![warn(
     missing_docs,
     missing_debug_implementations,
@@ -35,29 +36,61 @@
 //! visualized as below.
 //!
 //! ```text
-//!          mov_ptr  raw_ptr  len      capacity
-//! Any mem +--------+--------+--------+--------+
-//!         |¹0x0124 |²0x0122 |    2   |   4    |
-//!         +--------+--------+--------+--------+
+//!          raw_ptr  len      capacity
+//! Any mem +--------+--------+--------+
+//!         |¹0x0122 |    2   |   4    |
+//!         +--------+--------+--------+
 //!             |
 //!             v
 //! Heap    +--------+--------+--------+--------+
 //!         | uninit | uninit |    b   |   a    |
 //!         +--------+--------+--------+--------+
-//!             |
-//!             v
+//!             |                 ^
+//!             v                 | ² raw_ptr + capacity - len
 //! Pointer +--------+--------+--------+--------+
 //!         | 0x0122 | 0x0123 | 0x0124 | 0x0125 |
 //!         +--------+--------+--------+--------+
 //!                                       <--
 //!
-//!         ¹ Beginning of array data initialization.
-//!         ² To allocate and deallocate an array.
+//!         ¹ To allocate and deallocate an array.
+//!         ² Beginning of array data initialization, computed from the length.
 //! ```
 //!
+//! # Features
+//!
+//! - `std` (default): implements `std::error::Error` for the error types
+//!   and `std::io::{BufRead, Read, Write}` for `Cev<u8>`.
+//!   Without it the crate is `no_std` and only depends on `alloc`.
+//!
 #[doc = include_str!("../README.md")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[macro_use]
+mod macros;
+
 mod alloc_err;
+mod array_cev;
 mod cev;
+mod cev_buf;
+mod cev_slice;
+mod cev_string;
+mod de_cev;
+mod front_buf;
+mod growth;
 mod raw_cev;
+mod small_cev;
+mod thin_cev;
 
-pub use crate::cev::Cev;
+pub use crate::alloc_err::{TryReserveError, TryReserveErrorKind};
+pub use crate::array_cev::{ArrayCev, CapacityError};
+pub use crate::cev::{Cev, Drain, ExtractIf, Splice};
+pub use crate::cev_buf::CevBuf;
+pub use crate::cev_slice::CevSlice;
+pub use crate::cev_string::{CevString, FromUtf8Error};
+pub use crate::de_cev::DeCev;
+pub use crate::growth::{Doubling, FixedIncrement, GrowthPolicy, OneAndHalf, PageRounded};
+pub use crate::small_cev::SmallCev;
+pub use crate::thin_cev::ThinCev;
+pub use allocator_api2::alloc::{AllocError, Allocator, Global};
@@ -0,0 +1,100 @@
+macro_rules! impl_slice_eq {
+    ([$($vars:tt)*] $lhs:ty, $rhs:ty $(where $ty:ty: $bound:ident)?) => {
+        #[allow(clippy::partialeq_ne_impl)]
+        impl<T, U, $($vars)*> PartialEq<$rhs> for $lhs
+        where
+            T: PartialEq<U>,
+            $($ty: $bound)?
+        {
+            #[inline]
+            fn eq(&self, other: &$rhs) -> bool { self[..] == other[..] }
+            #[inline]
+            fn ne(&self, other: &$rhs) -> bool { self[..] != other[..] }
+        }
+    }
+}
+
+/// Implements the traits of a `Cev` variant that only forward to its `[T]` contents:
+/// `Deref`, `DerefMut`, `AsRef`, `AsMut`, `Debug`, `Hash`, `Eq`, `PartialOrd`, `Ord`
+/// and `IntoIterator` for references.
+///
+/// The type needs `as_slice` and `as_mut_slice` methods, and `PartialEq` with itself
+/// from `impl_slice_eq!` for `Eq`.
+macro_rules! impl_slice_traits {
+    ([$($vars:tt)*] $ty:ty) => {
+        impl<T, $($vars)*> core::ops::Deref for $ty {
+            type Target = [T];
+
+            #[inline]
+            fn deref(&self) -> &[T] {
+                self.as_slice()
+            }
+        }
+
+        impl<T, $($vars)*> core::ops::DerefMut for $ty {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut [T] {
+                self.as_mut_slice()
+            }
+        }
+
+        impl<T, $($vars)*> AsRef<[T]> for $ty {
+            fn as_ref(&self) -> &[T] {
+                self
+            }
+        }
+
+        impl<T, $($vars)*> AsMut<[T]> for $ty {
+            fn as_mut(&mut self) -> &mut [T] {
+                self
+            }
+        }
+
+        impl<T: core::fmt::Debug, $($vars)*> core::fmt::Debug for $ty {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Debug::fmt(&**self, f)
+            }
+        }
+
+        impl<T: core::hash::Hash, $($vars)*> core::hash::Hash for $ty {
+            #[inline]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                core::hash::Hash::hash(&**self, state)
+            }
+        }
+
+        impl<T: Eq, $($vars)*> Eq for $ty {}
+
+        impl<T: PartialOrd, $($vars)*> PartialOrd for $ty {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                PartialOrd::partial_cmp(&**self, &**other)
+            }
+        }
+
+        impl<T: Ord, $($vars)*> Ord for $ty {
+            #[inline]
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                Ord::cmp(&**self, &**other)
+            }
+        }
+
+        impl<'a, T, $($vars)*> IntoIterator for &'a $ty {
+            type Item = &'a T;
+            type IntoIter = core::slice::Iter<'a, T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl<'a, T, $($vars)*> IntoIterator for &'a mut $ty {
+            type Item = &'a mut T;
+            type IntoIter = core::slice::IterMut<'a, T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_mut()
+            }
+        }
+    };
+}
@@ -1,137 +1,169 @@
-use crate::alloc_err::{AllocError, TryReserveError, TryReserveErrorKind};
-use core::cmp;
-use std::alloc::{alloc, dealloc, handle_alloc_error, Layout, LayoutError};
-use std::mem;
-use std::ptr::{self, NonNull};
+use crate::alloc_err::{TryReserveError, TryReserveErrorKind};
+use crate::growth::{amortized_capacity, Doubling, GrowthPolicy};
+use alloc::alloc::handle_alloc_error;
+use allocator_api2::alloc::{AllocError, Allocator, Global};
+use core::alloc::{Layout, LayoutError};
+use core::mem::{self, ManuallyDrop};
+use core::ptr::{self, NonNull};
 
 #[cfg(test)]
 mod raw_cev_tests;
 
-pub(crate) struct RawCev<T> {
-    mov_ptr: NonNull<T>,
+pub(crate) struct RawCev<T, A: Allocator = Global, G: GrowthPolicy = Doubling> {
     raw_ptr: NonNull<T>,
     cap: usize,
+    alloc: A,
+    growth: G,
 }
 
-unsafe impl<T: Send> Send for RawCev<T> {}
-unsafe impl<T: Sync> Sync for RawCev<T> {}
+unsafe impl<T: Send, A: Allocator + Send, G: GrowthPolicy + Send> Send for RawCev<T, A, G> {}
+unsafe impl<T: Sync, A: Allocator + Sync, G: GrowthPolicy + Sync> Sync for RawCev<T, A, G> {}
 
-impl<T> RawCev<T> {
-    const MIN_NON_ZERO_CAP: usize = if mem::size_of::<T>() == 1 {
-        8
-    } else if mem::size_of::<T>() <= 1024 {
-        4
-    } else {
-        1
-    };
-
-    pub const IS_ZST: bool = is_zst::<T>();
+impl<T> RawCev<T, Global> {
     pub const NEW: Self = Self::new();
 
     pub const fn new() -> Self {
-        Self {
-            mov_ptr: NonNull::dangling(),
-            raw_ptr: NonNull::dangling(),
-            cap: 0,
-        }
+        Self::new_in(Global)
     }
 
     #[inline]
     pub fn with_capacity(capacity: usize) -> Self {
-        Self::allocate(capacity)
+        Self::with_capacity_in(capacity, Global)
     }
 
     #[inline]
-    pub fn ptr(&self) -> *mut T {
-        self.mov_ptr.as_ptr()
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        Self::try_with_capacity_in(capacity, Global)
     }
 
     #[inline]
-    pub unsafe fn mov_ptr(&mut self, ptr: *mut T) {
-        self.mov_ptr = NonNull::new_unchecked(ptr);
+    pub unsafe fn from_raw_parts_ptr(raw_ptr: *mut T, cap: usize) -> Self {
+        Self::from_raw_parts_ptr_in(raw_ptr, cap, Global)
     }
+}
 
-    #[inline]
-    pub unsafe fn mov_ptr_add(&mut self, count: usize) {
-        self.mov_ptr = NonNull::new_unchecked(self.ptr().add(count));
+impl<T, A: Allocator> RawCev<T, A> {
+    pub const fn new_in(alloc: A) -> Self {
+        Self {
+            raw_ptr: NonNull::dangling(),
+            cap: 0,
+            alloc,
+            growth: Doubling,
+        }
     }
 
     #[inline]
-    pub unsafe fn mov_ptr_sub(&mut self, count: usize) {
-        self.mov_ptr = NonNull::new_unchecked(self.ptr().sub(count));
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        Self::allocate_in(capacity, alloc)
     }
 
     #[inline]
-    pub fn raw_ptr(&self) -> *mut T {
-        self.raw_ptr.as_ptr()
-    }
-
-    #[inline(always)]
-    pub fn capacity(&self) -> usize {
-        if Self::IS_ZST {
-            usize::MAX
-        } else {
-            self.cap
-        }
+    pub fn try_with_capacity_in(capacity: usize, alloc: A) -> Result<Self, TryReserveError> {
+        Self::try_allocate_in(capacity, alloc)
     }
 
     #[inline]
-    unsafe fn from_raw_parts(mov_ptr: NonNull<T>, raw_ptr: NonNull<T>, cap: usize) -> Self {
+    pub unsafe fn from_raw_parts_ptr_in(raw_ptr: *mut T, cap: usize, alloc: A) -> Self {
         Self {
-            mov_ptr,
-            raw_ptr,
+            raw_ptr: NonNull::new_unchecked(raw_ptr),
             cap,
+            alloc,
+            growth: Doubling,
         }
     }
 
-    #[inline]
-    pub unsafe fn from_raw_parts_ptr(mov_ptr: *mut T, raw_ptr: *mut T, cap: usize) -> Self {
-        RawCev::from_raw_parts(
-            NonNull::new_unchecked(mov_ptr),
-            NonNull::new_unchecked(raw_ptr),
-            cap,
-        )
+    fn allocate_in(capacity: usize, alloc: A) -> Self {
+        match Self::try_allocate_in(capacity, alloc) {
+            Ok(this) => this,
+            Err(err) => handle_error(err),
+        }
     }
 
-    fn allocate(capacity: usize) -> Self {
+    fn try_allocate_in(capacity: usize, alloc: A) -> Result<Self, TryReserveError> {
         if Self::IS_ZST || capacity == 0 {
-            Self::new()
+            Ok(Self::new_in(alloc))
         } else {
-            let layout = match Layout::array::<T>(capacity) {
-                Ok(layout) => layout,
-                Err(_) => capacity_overflow(),
-            };
+            let layout =
+                Layout::array::<T>(capacity).map_err(|_| TryReserveErrorKind::CapacityOverflow)?;
 
-            match alloc_guard(layout.size()) {
-                Ok(_) => {}
-                Err(_) => capacity_overflow(),
-            }
+            alloc_guard(layout.size())?;
 
-            let ptr = unsafe { alloc_usr(layout) };
+            let ptr = alloc.allocate(layout).map_err(|_| {
+                TryReserveError::from(TryReserveErrorKind::AllocError {
+                    layout,
+                    non_exhaustive: (),
+                })
+            })?;
+            let ptr = ptr.as_ptr().cast::<T>();
 
-            Self {
-                mov_ptr: unsafe { NonNull::new_unchecked(ptr.cast::<T>().add(capacity - 1)) },
-                raw_ptr: unsafe { NonNull::new_unchecked(ptr.cast::<T>()) },
+            Ok(Self {
+                raw_ptr: unsafe { NonNull::new_unchecked(ptr) },
                 cap: capacity,
-            }
+                alloc,
+                growth: Doubling,
+            })
+        }
+    }
+}
+
+impl<T, A: Allocator, G: GrowthPolicy> RawCev<T, A, G> {
+    pub const IS_ZST: bool = is_zst::<T>();
+
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
+    #[inline]
+    pub fn growth_policy(&self) -> &G {
+        &self.growth
+    }
+
+    #[inline]
+    pub fn growth_policy_mut(&mut self) -> &mut G {
+        &mut self.growth
+    }
+
+    pub fn with_growth_policy<H: GrowthPolicy>(self, growth: H) -> RawCev<T, A, H> {
+        let this = ManuallyDrop::new(self);
+        unsafe {
+            let old = ptr::read(&this.growth);
+            let new = RawCev {
+                raw_ptr: this.raw_ptr,
+                cap: this.cap,
+                alloc: ptr::read(&this.alloc),
+                growth,
+            };
+            drop(old);
+            new
+        }
+    }
+
+    #[inline]
+    pub fn raw_ptr(&self) -> *mut T {
+        self.raw_ptr.as_ptr()
+    }
+
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        if Self::IS_ZST {
+            usize::MAX
+        } else {
+            self.cap
         }
     }
 
-    fn allocated_memory(&self) -> Option<(NonNull<u8>, NonNull<u8>, Layout)> {
+    fn allocated_memory(&self) -> Option<(NonNull<u8>, Layout)> {
         if Self::IS_ZST || self.cap == 0 {
             None
         } else {
             #[allow(clippy::let_unit_value)]
-            let _: () = { assert!(mem::size_of::<T>() % mem::align_of::<T>() == 0) };
+            let _: () = { assert!(mem::size_of::<T>().is_multiple_of(mem::align_of::<T>())) };
             unsafe {
                 let align = mem::align_of::<T>();
                 let size = mem::size_of::<T>() * self.cap;
                 let layout = Layout::from_size_align_unchecked(size, align);
-                Some((
-                    NonNull::new_unchecked(self.raw_ptr.as_ptr().cast::<u8>()),
-                    NonNull::new_unchecked(self.mov_ptr.as_ptr().cast::<u8>()),
-                    layout,
-                ))
+                Some((self.raw_ptr.cast::<u8>(), layout))
             }
         }
     }
@@ -143,7 +175,11 @@ impl<T> RawCev<T> {
     #[inline]
     pub fn reserve(&mut self, len: usize, additional: usize) {
         #[cold]
-        fn do_reserve_and_handle<T>(slf: &mut RawCev<T>, len: usize, additional: usize) {
+        fn do_reserve_and_handle<T, A: Allocator, G: GrowthPolicy>(
+            slf: &mut RawCev<T, A, G>,
+            len: usize,
+            additional: usize,
+        ) {
             handle_reserve(slf.grow_amortized(len, additional));
         }
 
@@ -152,6 +188,36 @@ impl<T> RawCev<T> {
         }
     }
 
+    pub fn reserve_exact(&mut self, len: usize, additional: usize) {
+        if self.needs_to_grow(len, additional) {
+            handle_reserve(self.grow_exact(len, additional));
+        }
+    }
+
+    pub fn try_reserve(&mut self, len: usize, additional: usize) -> Result<(), TryReserveError> {
+        if self.needs_to_grow(len, additional) {
+            self.grow_amortized(len, additional)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn try_reserve_exact(
+        &mut self,
+        len: usize,
+        additional: usize,
+    ) -> Result<(), TryReserveError> {
+        if self.needs_to_grow(len, additional) {
+            self.grow_exact(len, additional)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn shrink_to_fit(&mut self, len: usize, cap: usize) {
+        handle_reserve(self.shrink(len, cap));
+    }
+
     fn needs_to_grow(&self, len: usize, additional: usize) -> bool {
         additional > self.capacity().wrapping_sub(len)
     }
@@ -166,39 +232,103 @@ impl<T> RawCev<T> {
         let required_cap = len
             .checked_add(additional)
             .ok_or(TryReserveErrorKind::CapacityOverflow)?;
-        let cap = cmp::max(self.cap * 2, required_cap);
-        let cap = cmp::max(Self::MIN_NON_ZERO_CAP, cap);
+        let cap = amortized_capacity(&self.growth, self.cap, required_cap, mem::size_of::<T>());
         let new_layout = Layout::array::<T>(cap);
-        let ptr = finish_increase(new_layout, self.allocated_memory(), mem::size_of::<T>())?;
+        let ptr = finish_increase(
+            new_layout,
+            self.allocated_memory(),
+            len * mem::size_of::<T>(),
+            &self.alloc,
+        )?;
+
+        self.raw_ptr = ptr.cast::<T>();
+        self.cap = cap;
+        Ok(())
+    }
+
+    fn grow_exact(&mut self, len: usize, additional: usize) -> Result<(), TryReserveError> {
+        if Self::IS_ZST {
+            return Err(TryReserveErrorKind::CapacityOverflow.into());
+        }
 
-        self.raw_ptr = ptr.0.cast::<T>();
-        self.mov_ptr = ptr.1.cast::<T>();
+        let cap = len
+            .checked_add(additional)
+            .ok_or(TryReserveErrorKind::CapacityOverflow)?;
+        let new_layout = Layout::array::<T>(cap);
+        let ptr = finish_increase(
+            new_layout,
+            self.allocated_memory(),
+            len * mem::size_of::<T>(),
+            &self.alloc,
+        )?;
+
+        self.raw_ptr = ptr.cast::<T>();
         self.cap = cap;
         Ok(())
     }
+
+    fn shrink(&mut self, len: usize, cap: usize) -> Result<(), TryReserveError> {
+        assert!(
+            cap <= self.capacity(),
+            "Tried to shrink to a larger capacity"
+        );
+        debug_assert!(len <= cap);
+
+        let (ptr, layout) = if let Some(mem) = self.allocated_memory() {
+            mem
+        } else {
+            return Ok(());
+        };
+
+        unsafe {
+            if cap == 0 {
+                self.alloc.deallocate(ptr, layout);
+                self.raw_ptr = NonNull::dangling();
+                self.cap = 0;
+                return Ok(());
+            }
+
+            let old_mov_ptr = self.raw_ptr().add(self.cap - len);
+            let new_mov_ptr = self.raw_ptr().add(cap - len);
+            ptr::copy(old_mov_ptr, new_mov_ptr, len);
+
+            let new_layout =
+                Layout::from_size_align_unchecked(mem::size_of::<T>() * cap, layout.align());
+            let new_ptr = match self.alloc.shrink(ptr, layout, new_layout) {
+                Ok(new_ptr) => new_ptr,
+                Err(_) => {
+                    ptr::copy(new_mov_ptr, old_mov_ptr, len);
+                    return Err(TryReserveErrorKind::AllocError {
+                        layout: new_layout,
+                        non_exhaustive: (),
+                    }
+                    .into());
+                }
+            };
+
+            self.raw_ptr = new_ptr.cast::<T>();
+            self.cap = cap;
+        }
+        Ok(())
+    }
 }
 
 #[inline(never)]
-fn finish_increase(
+fn finish_increase<A: Allocator>(
     new_layout: Result<Layout, LayoutError>,
-    allocated_memory: Option<(NonNull<u8>, NonNull<u8>, Layout)>,
-    offset: usize,
-) -> Result<(NonNull<u8>, NonNull<u8>), TryReserveError> {
+    allocated_memory: Option<(NonNull<u8>, Layout)>,
+    len: usize,
+    alloc: &A,
+) -> Result<NonNull<u8>, TryReserveError> {
     let new_layout = new_layout.map_err(|_| TryReserveErrorKind::CapacityOverflow)?;
 
     alloc_guard(new_layout.size())?;
 
-    let memory = if let Some((ptr, mov_ptr, old_layout)) = allocated_memory {
+    let memory = if let Some((ptr, old_layout)) = allocated_memory {
         debug_assert_eq!(old_layout.align(), new_layout.align());
-        unsafe { increase((ptr, mov_ptr), old_layout, new_layout) }
+        unsafe { increase(ptr, len, old_layout, new_layout, alloc) }
     } else {
-        unsafe {
-            let ptr = alloc_usr(new_layout);
-            let mov_ptr = NonNull::new_unchecked(ptr.add(new_layout.size() - offset));
-            let raw_ptr = NonNull::new_unchecked(ptr);
-
-            Ok((raw_ptr, mov_ptr))
-        }
+        alloc.allocate(new_layout).map(|ptr| ptr.cast::<u8>())
     };
 
     memory.map_err(|_| {
@@ -210,36 +340,38 @@ fn finish_increase(
     })
 }
 
-unsafe fn increase(
-    ptr: (NonNull<u8>, NonNull<u8>),
+/// Grows the block with `Allocator::grow`, which extends it in place when possible,
+/// then moves the live tail of `len` bytes to the end of the grown block.
+unsafe fn increase<A: Allocator>(
+    ptr: NonNull<u8>,
+    len: usize,
     old_layout: Layout,
     new_layout: Layout,
-) -> Result<(NonNull<u8>, NonNull<u8>), AllocError> {
+    alloc: &A,
+) -> Result<NonNull<u8>, AllocError> {
     debug_assert!(
         new_layout.size() >= old_layout.size(),
         "`new_layout.size()` must be greater than or equal to `old_layout.size()`"
     );
 
-    let len = old_layout.size() - ptr.1.as_ptr().offset_from(ptr.0.as_ptr()) as usize;
-    let ptr_offset_val = new_layout.size() - len;
-    let new_ptr = alloc_usr(new_layout);
-    let new_mov_ptr = new_ptr.add(ptr_offset_val);
+    let new_ptr = alloc.grow(ptr, old_layout, new_layout)?.cast::<u8>();
+    let raw_ptr = new_ptr.as_ptr();
 
     unsafe {
-        ptr::copy_nonoverlapping(ptr.1.as_ptr(), new_ptr.add(ptr_offset_val), len);
-        dealloc(ptr.0.as_ptr(), old_layout);
+        ptr::copy(
+            raw_ptr.add(old_layout.size() - len),
+            raw_ptr.add(new_layout.size() - len),
+            len,
+        );
     }
 
-    Ok((
-        NonNull::new_unchecked(new_ptr),
-        NonNull::new_unchecked(new_mov_ptr),
-    ))
+    Ok(new_ptr)
 }
 
-impl<T> Drop for RawCev<T> {
+impl<T, A: Allocator, G: GrowthPolicy> Drop for RawCev<T, A, G> {
     fn drop(&mut self) {
-        if let Some((ptr, _, layout)) = self.allocated_memory() {
-            unsafe { dealloc(ptr.as_ptr(), layout) }
+        if let Some((ptr, layout)) = self.allocated_memory() {
+            unsafe { self.alloc.deallocate(ptr, layout) }
         }
     }
 }
@@ -262,19 +394,16 @@ const fn is_zst<U>() -> bool {
 }
 
 #[inline]
-fn handle_reserve(result: Result<(), TryReserveError>) {
-    match result.map_err(|e| e.kind()) {
-        Err(TryReserveErrorKind::CapacityOverflow) => capacity_overflow(),
-        Err(TryReserveErrorKind::AllocError { layout, .. }) => handle_alloc_error(layout),
-        Ok(()) => {}
+pub(crate) fn handle_reserve(result: Result<(), TryReserveError>) {
+    if let Err(err) = result {
+        handle_error(err);
     }
 }
 
-unsafe fn alloc_usr(layout: Layout) -> *mut u8 {
-    let ptr = alloc(layout);
-
-    if ptr.is_null() {
-        handle_alloc_error(layout);
+#[cold]
+fn handle_error(err: TryReserveError) -> ! {
+    match err.kind() {
+        TryReserveErrorKind::CapacityOverflow => capacity_overflow(),
+        TryReserveErrorKind::AllocError { layout, .. } => handle_alloc_error(layout),
     }
-    ptr
 }
@@ -1,10 +1,10 @@
 use crate::raw_cev::*;
 
-struct ZST;
+struct Zst;
 
 fn zst_sanity<T>(v: &RawCev<T>) {
     assert_eq!(v.capacity(), usize::MAX);
-    assert_eq!(v.ptr(), core::ptr::NonNull::<T>::dangling().as_ptr());
+    assert_eq!(v.raw_ptr(), core::ptr::NonNull::<T>::dangling().as_ptr());
     assert_eq!(v.allocated_memory(), None);
 }
 
@@ -12,28 +12,27 @@ fn zst_sanity<T>(v: &RawCev<T>) {
 fn zst() {
     let cap_err = Err(TryReserveErrorKind::CapacityOverflow.into());
 
-    assert_eq!(std::mem::size_of::<ZST>(), 0);
+    assert_eq!(core::mem::size_of::<Zst>(), 0);
 
-    let v: RawCev<ZST> = RawCev::new();
+    let v: RawCev<Zst> = RawCev::new();
     zst_sanity(&v);
 
-    let v: RawCev<ZST> = RawCev::with_capacity(100);
+    let v: RawCev<Zst> = RawCev::with_capacity(100);
     zst_sanity(&v);
 
-    let v: RawCev<ZST> = RawCev::with_capacity(100);
+    let v: RawCev<Zst> = RawCev::with_capacity(100);
     zst_sanity(&v);
 
-    let v: RawCev<ZST> = RawCev::allocate(0);
+    let v: RawCev<Zst> = RawCev::allocate_in(0, Global);
     zst_sanity(&v);
 
-    let v: RawCev<ZST> = RawCev::allocate(100);
+    let v: RawCev<Zst> = RawCev::allocate_in(100, Global);
     zst_sanity(&v);
 
-    let mut v: RawCev<ZST> = RawCev::allocate(usize::MAX);
+    let mut v: RawCev<Zst> = RawCev::allocate_in(usize::MAX, Global);
     zst_sanity(&v);
 
     assert_eq!(v.grow_amortized(100, usize::MAX - 100), cap_err);
     assert_eq!(v.grow_amortized(101, usize::MAX - 100), cap_err);
     zst_sanity(&v);
 }
-
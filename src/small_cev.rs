@@ -0,0 +1,548 @@
+use crate::alloc_err::{TryReserveError, TryReserveErrorKind};
+use crate::cev::Cev;
+use crate::front_buf::FrontBuf;
+use crate::raw_cev::handle_reserve;
+use core::cmp;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ptr;
+use core::slice;
+
+/// A `Cev` array that stores up to `N` elements inline and spills to the heap
+/// when it needs more room.
+///
+/// Inline elements are anchored at the end of the inline array, the same as a heap `Cev` array,
+/// so [`push`] adds to the beginning without moving the other elements.
+/// Once spilled, the `SmallCev` array holds a regular [`Cev`] and converts to and from it
+/// without copying.
+///
+/// [`push`]: SmallCev::push
+///
+/// # Examples
+///
+/// ```
+/// use cev::SmallCev;
+///
+/// let mut small: SmallCev<u8, 4> = SmallCev::new();
+/// small.push(2);
+/// small.push(1);
+/// assert_eq!(small, [1, 2]);
+/// assert!(!small.spilled());
+///
+/// small.append(&mut SmallCev::from([0; 4]));
+/// assert_eq!(small, [0, 0, 0, 0, 1, 2]);
+/// assert!(small.spilled());
+/// ```
+pub struct SmallCev<T, const N: usize> {
+    data: SmallCevData<T, N>,
+}
+
+enum SmallCevData<T, const N: usize> {
+    Inline(FrontBuf<T, [MaybeUninit<T>; N]>),
+    Heap(Cev<T>),
+}
+
+impl<T, const N: usize> SmallCev<T, N> {
+    /// Adds elements to the beginning of the `SmallCev` array, moving them from another
+    /// `SmallCev` array, leaving the other empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::SmallCev;
+    ///
+    /// let mut small: SmallCev<_, 8> = SmallCev::from([4, 5, 6]);
+    /// let mut small_other = SmallCev::from([1, 2, 3]);
+    /// small.append(&mut small_other);
+    /// assert_eq!(small, [1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(small_other, []);
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        let count = other.len();
+        self.reserve(count);
+
+        unsafe {
+            let len = self.len();
+            let dst = match &mut self.data {
+                SmallCevData::Inline(inline) => inline.as_mut_ptr().sub(count),
+                SmallCevData::Heap(cev) => {
+                    let spare = cev.spare_capacity_mut();
+                    spare.as_mut_ptr().add(spare.len() - count) as *mut T
+                }
+            };
+            ptr::copy_nonoverlapping(other.as_ptr(), dst, count);
+            other.set_len(0);
+            self.set_len(len + count);
+        }
+    }
+
+    /// Returns an unsafe mutable pointer to the first element.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        match &mut self.data {
+            SmallCevData::Inline(inline) => inline.as_mut_ptr(),
+            SmallCevData::Heap(cev) => cev.as_mut_ptr(),
+        }
+    }
+
+    /// Extracts a mutable slice of the entire `SmallCev` array.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let len = self.len();
+        unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), len) }
+    }
+
+    /// Returns an unsafe pointer to the first element.
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        match &self.data {
+            SmallCevData::Inline(inline) => inline.as_ptr(),
+            SmallCevData::Heap(cev) => cev.as_ptr(),
+        }
+    }
+
+    /// Extracts a slice of the entire `SmallCev` array.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.as_ptr(), self.len()) }
+    }
+
+    /// Total number of elements the `SmallCev` array can hold without reallocating,
+    /// `N` while the elements are stored inline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::SmallCev;
+    ///
+    /// let small: SmallCev<u32, 8> = SmallCev::new();
+    /// assert_eq!(small.capacity(), 8);
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        match &self.data {
+            SmallCevData::Inline(_) => N,
+            SmallCevData::Heap(cev) => cev.capacity(),
+        }
+    }
+
+    /// Clears the `SmallCev` array, removing all elements.
+    /// A spilled array keeps its heap memory.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Inserts an element at position `index` within the `SmallCev` array, shifting all
+    /// elements before it to the left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::SmallCev;
+    ///
+    /// let mut small: SmallCev<_, 4> = SmallCev::from([2, 2, 3]);
+    /// small.insert(1, 0);
+    /// assert_eq!(small, [2, 0, 2, 3]);
+    /// small.insert(4, 8);
+    /// assert_eq!(small, [2, 0, 2, 3, 8]);
+    /// assert!(small.spilled());
+    /// ```
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, element: T) {
+        match &mut self.data {
+            SmallCevData::Inline(inline) => {
+                if let Err(element) = inline.try_insert(index, element) {
+                    self.spill(1);
+                    self.insert(index, element);
+                }
+            }
+            SmallCevData::Heap(cev) => cev.insert(index, element),
+        }
+    }
+
+    /// Converts the `SmallCev` array into a `Cev` array.
+    /// A spilled array is returned without copying,
+    /// inline elements are moved to a new allocation of the exact length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::{Cev, SmallCev};
+    ///
+    /// let small: SmallCev<_, 2> = SmallCev::from([1, 2]);
+    /// let cev: Cev<_> = small.into_cev();
+    /// assert_eq!(cev, [1, 2]);
+    /// assert_eq!(cev.capacity(), 2);
+    /// ```
+    pub fn into_cev(self) -> Cev<T> {
+        let mut this = ManuallyDrop::new(self);
+        match &mut this.data {
+            SmallCevData::Inline(inline) => {
+                let len = inline.len();
+                let mut cev = Cev::with_capacity(len);
+                unsafe {
+                    let dst = cev.spare_capacity_mut().as_mut_ptr() as *mut T;
+                    ptr::copy_nonoverlapping(inline.as_ptr(), dst, len);
+                    cev.set_len_ptr(len);
+                }
+                cev
+            }
+            SmallCevData::Heap(cev) => unsafe { ptr::read(cev) },
+        }
+    }
+
+    /// Returns `true` if the `SmallCev` array contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// `SmallCev` array length, the number of elements in the array.
+    #[inline]
+    pub fn len(&self) -> usize {
+        match &self.data {
+            SmallCevData::Inline(inline) => inline.len(),
+            SmallCevData::Heap(cev) => cev.len(),
+        }
+    }
+
+    /// Creates a new, empty `SmallCev<T, N>` storing its elements inline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::SmallCev;
+    ///
+    /// let small: SmallCev<i32, 4> = SmallCev::new();
+    /// assert!(small.is_empty());
+    /// assert!(!small.spilled());
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        SmallCev {
+            data: SmallCevData::Inline(FrontBuf::new([const { MaybeUninit::uninit() }; N])),
+        }
+    }
+
+    /// Removes the first element from the `SmallCev` array and returns it,
+    /// or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::SmallCev;
+    ///
+    /// let mut small: SmallCev<_, 2> = SmallCev::from([1, 2]);
+    /// assert_eq!(small.pop(), Some(1));
+    /// assert_eq!(small, [2]);
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        match &mut self.data {
+            SmallCevData::Inline(inline) => inline.pop(),
+            SmallCevData::Heap(cev) => cev.pop(),
+        }
+    }
+
+    /// Adds an element to the beginning of the `SmallCev` array,
+    /// spilling to the heap when the inline storage is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::SmallCev;
+    ///
+    /// let mut small: SmallCev<_, 2> = SmallCev::new();
+    /// small.push(2);
+    /// small.push(1);
+    /// assert!(!small.spilled());
+    /// small.push(0);
+    /// assert!(small.spilled());
+    /// assert_eq!(small, [0, 1, 2]);
+    /// ```
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        match &mut self.data {
+            SmallCevData::Inline(inline) => {
+                if let Err(value) = inline.push(value) {
+                    self.spill(1);
+                    self.push(value);
+                }
+            }
+            SmallCevData::Heap(cev) => cev.push(value),
+        }
+    }
+
+    /// Removes and returns the element at position `index` within the `SmallCev` array,
+    /// shifting all elements before it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::SmallCev;
+    ///
+    /// let mut small: SmallCev<_, 4> = SmallCev::from([1, 2, 3]);
+    /// assert_eq!(small.remove(1), 2);
+    /// assert_eq!(small, [1, 3]);
+    /// ```
+    #[track_caller]
+    pub fn remove(&mut self, index: usize) -> T {
+        match &mut self.data {
+            SmallCevData::Inline(inline) => inline.remove(index),
+            SmallCevData::Heap(cev) => cev.remove(index),
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more elements,
+    /// spilling to the heap if they do not fit inline.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::SmallCev;
+    ///
+    /// let mut small: SmallCev<u8, 4> = SmallCev::from([1]);
+    /// small.reserve(3);
+    /// assert!(!small.spilled());
+    /// small.reserve(4);
+    /// assert!(small.spilled());
+    /// assert!(small.capacity() >= 5);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        handle_reserve(self.try_reserve(additional));
+    }
+
+    /// Returns `true` if the elements are stored on the heap.
+    #[inline]
+    pub fn spilled(&self) -> bool {
+        matches!(self.data, SmallCevData::Heap(_))
+    }
+
+    /// Reduces the length of the `SmallCev` array to `len`,
+    /// by removing elements from the beginning of the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::SmallCev;
+    ///
+    /// let mut small: SmallCev<_, 8> = SmallCev::from(['T', 'r', 'u', 'n', 'c', 'a', 't', 'e']);
+    /// small.truncate(4);
+    /// assert_eq!(small, ['c', 'a', 't', 'e']);
+    /// ```
+    pub fn truncate(&mut self, new_len: usize) {
+        match &mut self.data {
+            SmallCevData::Inline(inline) => inline.truncate(new_len),
+            SmallCevData::Heap(cev) => cev.truncate(new_len),
+        }
+    }
+
+    /// Tries to reserve capacity for at least `additional` more elements,
+    /// spilling to the heap if they do not fit inline.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::SmallCev;
+    ///
+    /// let mut small: SmallCev<u32, 4> = SmallCev::new();
+    /// assert!(small.try_reserve(10).is_ok());
+    /// assert!(small.try_reserve(usize::MAX).is_err());
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        match &mut self.data {
+            SmallCevData::Inline(inline) if N - inline.len() >= additional => Ok(()),
+            SmallCevData::Inline(_) => self.try_spill(additional),
+            SmallCevData::Heap(cev) => cev.try_reserve(additional),
+        }
+    }
+
+    /// Sets the length without dropping or initializing any element.
+    unsafe fn set_len(&mut self, new_len: usize) {
+        match &mut self.data {
+            SmallCevData::Inline(inline) => inline.set_len(new_len),
+            SmallCevData::Heap(cev) => cev.set_len_ptr(new_len),
+        }
+    }
+
+    /// Moves the inline elements to the heap, reserving room for `additional` more.
+    #[cold]
+    #[inline(never)]
+    fn spill(&mut self, additional: usize) {
+        handle_reserve(self.try_spill(additional));
+    }
+
+    fn try_spill(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let SmallCevData::Inline(inline) = &mut self.data else {
+            return Ok(());
+        };
+
+        let len = inline.len();
+        let required = len
+            .checked_add(additional)
+            .ok_or(TryReserveErrorKind::CapacityOverflow)?;
+        let mut cev = Cev::try_with_capacity(cmp::max(required, N.saturating_mul(2)))?;
+        unsafe {
+            let spare = cev.spare_capacity_mut();
+            let dst = spare.as_mut_ptr().add(spare.len() - len) as *mut T;
+            ptr::copy_nonoverlapping(inline.as_ptr(), dst, len);
+            cev.set_len_ptr(len);
+            inline.set_len(0);
+        }
+        self.data = SmallCevData::Heap(cev);
+        Ok(())
+    }
+}
+
+impl_slice_traits! { [const N: usize] SmallCev<T, N> }
+
+impl_slice_eq! { [const N: usize, const M: usize] SmallCev<T, N>, SmallCev<U, M> }
+impl_slice_eq! { [const N: usize] SmallCev<T, N>, [U] }
+impl_slice_eq! { [const N: usize] SmallCev<T, N>, &[U] }
+impl_slice_eq! { [const N: usize, const M: usize] SmallCev<T, N>, [U; M] }
+
+impl<T, const N: usize> Default for SmallCev<T, N> {
+    fn default() -> SmallCev<T, N> {
+        SmallCev::new()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for SmallCev<T, N> {
+    fn clone(&self) -> Self {
+        let mut small = SmallCev::new();
+        small.reserve(self.len());
+        for elem in self.iter().rev() {
+            small.push(elem.clone());
+        }
+        small
+    }
+}
+
+impl<T, const N: usize> Extend<T> for SmallCev<T, N> {
+    /// Pushes every item of the iterator to the beginning of the `SmallCev` array,
+    /// so the items end up in reversed order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::SmallCev;
+    ///
+    /// let mut small: SmallCev<_, 4> = SmallCev::from([0]);
+    /// small.extend([1, 2, 3]);
+    /// assert_eq!(small, [3, 2, 1, 0]);
+    /// ```
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        self.reserve(lower);
+
+        for elem in iter {
+            self.push(elem);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a, const N: usize> Extend<&'a T> for SmallCev<T, N> {
+    /// Pushes a copy of every item of the iterator to the beginning of the `SmallCev` array,
+    /// so the items end up in reversed order.
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for SmallCev<T, N> {
+    /// Collects the items keeping their order.
+    ///
+    /// Up to `N` items are written straight into the inline storage without allocating.
+    /// If the lower bound of `size_hint` already exceeds `N`, the items are collected into
+    /// a `Cev` array, otherwise the array spills to the heap once the `N + 1`th item arrives.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::SmallCev;
+    ///
+    /// let small: SmallCev<_, 4> = (0..10).filter(|x| x % 3 == 0).collect();
+    /// assert_eq!(small, [0, 3, 6, 9]);
+    /// assert!(!small.spilled());
+    ///
+    /// let small: SmallCev<_, 4> = (0..10).filter(|x| x % 2 == 0).collect();
+    /// assert_eq!(small, [0, 2, 4, 6, 8]);
+    /// assert!(small.spilled());
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> SmallCev<T, N> {
+        let iter = iter.into_iter();
+        if iter.size_hint().0 > N {
+            return SmallCev::from(Cev::from_iter(iter));
+        }
+
+        // Pushing to the front reverses the items, which is undone in place.
+        let mut small = SmallCev::new();
+        small.extend(iter);
+        small.reverse();
+        small
+    }
+}
+
+impl<T, const N: usize> From<Cev<T>> for SmallCev<T, N> {
+    /// Wraps a `Cev` array as a spilled `SmallCev` array without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::{Cev, SmallCev};
+    ///
+    /// let small: SmallCev<_, 4> = SmallCev::from(Cev::from([1, 2]));
+    /// assert!(small.spilled());
+    /// assert_eq!(small, [1, 2]);
+    /// ```
+    fn from(cev: Cev<T>) -> SmallCev<T, N> {
+        SmallCev {
+            data: SmallCevData::Heap(cev),
+        }
+    }
+}
+
+impl<T, const N: usize> From<SmallCev<T, N>> for Cev<T> {
+    /// Converts a `SmallCev` array, see [`SmallCev::into_cev`].
+    fn from(small: SmallCev<T, N>) -> Cev<T> {
+        small.into_cev()
+    }
+}
+
+impl<T, const N: usize, const M: usize> From<[T; M]> for SmallCev<T, N> {
+    /// Moves the elements of the array inline if they fit, otherwise to the heap.
+    fn from(arr: [T; M]) -> SmallCev<T, N> {
+        if M > N {
+            return SmallCev::from(Cev::from(arr));
+        }
+
+        let mut small = SmallCev::new();
+        small.extend(arr.into_iter().rev());
+        small
+    }
+}
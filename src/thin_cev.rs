@@ -0,0 +1,490 @@
+use crate::alloc_err::{TryReserveError, TryReserveErrorKind};
+use crate::cev::Cev;
+use crate::growth::{amortized_capacity, Doubling};
+use crate::raw_cev::handle_reserve;
+use allocator_api2::alloc::{Allocator, Global};
+use core::alloc::Layout;
+use core::marker::PhantomData;
+use core::mem;
+use core::ptr::{self, NonNull};
+use core::slice;
+
+/// Length and capacity, stored at the start of the allocation.
+#[repr(C)]
+struct Header {
+    len: usize,
+    cap: usize,
+}
+
+/// Shared by all empty `ThinCev` arrays, it is never written.
+static EMPTY_HEADER: Header = Header { len: 0, cap: 0 };
+
+/// A `Cev` array that is one pointer wide.
+///
+/// The length and the capacity are stored in a header at the start of the allocation,
+/// followed by the elements, which are anchored at the end the same as in a `Cev` array,
+/// so [`push`] adds to the beginning without moving the other elements.
+/// An empty `ThinCev` array points to a shared static header and does not allocate,
+/// which makes it a good fit for sparse tables where most entries are empty.
+///
+/// [`push`]: ThinCev::push
+///
+/// # Examples
+///
+/// ```
+/// use cev::ThinCev;
+///
+/// let mut table: Vec<ThinCev<u32>> = (0..1000).map(|_| ThinCev::new()).collect();
+/// table[7].push(2);
+/// table[7].push(1);
+/// assert_eq!(table[7], [1, 2]);
+/// assert_eq!(std::mem::size_of::<ThinCev<u32>>(), std::mem::size_of::<usize>());
+/// ```
+pub struct ThinCev<T> {
+    ptr: NonNull<Header>,
+    marker: PhantomData<T>,
+}
+
+unsafe impl<T: Send> Send for ThinCev<T> {}
+unsafe impl<T: Sync> Sync for ThinCev<T> {}
+
+impl<T> ThinCev<T> {
+    const IS_ZST: bool = mem::size_of::<T>() == 0;
+
+    /// Returns an unsafe mutable pointer to the first element.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr()
+    }
+
+    /// Extracts a mutable slice of the entire `ThinCev` array.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.ptr(), self.len()) }
+    }
+
+    /// Returns an unsafe pointer to the first element.
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr()
+    }
+
+    /// Extracts a slice of the entire `ThinCev` array.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.ptr(), self.len()) }
+    }
+
+    /// Number of elements the `ThinCev` array can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.header().cap
+    }
+
+    /// Clears the `ThinCev` array, removing all elements. The allocation is kept.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Inserts an element at position `index` within the `ThinCev` array, shifting all
+    /// elements before it to the left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`, or if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::ThinCev;
+    ///
+    /// let mut thin = ThinCev::from(cev::Cev::from(['a', 'c']));
+    /// thin.insert(1, 'b');
+    /// thin.insert(3, 'd');
+    /// assert_eq!(thin, ['a', 'b', 'c', 'd']);
+    /// ```
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, element: T) {
+        #[cold]
+        #[inline(never)]
+        #[track_caller]
+        fn assert_failed(index: usize, len: usize) -> ! {
+            panic!("insertion index (is {index}) should be <= len (is {len})");
+        }
+
+        let len = self.len();
+        if index > len {
+            assert_failed(index, len);
+        }
+        if len == self.capacity() {
+            self.grow_one();
+        }
+
+        unsafe {
+            let p = self.ptr();
+            ptr::copy(p, p.sub(1), index);
+            ptr::write(p.sub(1).add(index), element);
+            self.set_len(len + 1);
+        }
+    }
+
+    /// Returns `true` if the `ThinCev` array contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// `ThinCev` array length, the number of elements in the array.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.header().len
+    }
+
+    /// Creates a new, empty `ThinCev` array. No memory is allocated until elements are pushed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::ThinCev;
+    ///
+    /// let thin: ThinCev<i32> = ThinCev::new();
+    /// assert_eq!(thin.capacity(), 0);
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        ThinCev {
+            ptr: unsafe { NonNull::new_unchecked(&EMPTY_HEADER as *const Header as *mut Header) },
+            marker: PhantomData,
+        }
+    }
+
+    /// Removes the first element from the `ThinCev` array and returns it,
+    /// or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::ThinCev;
+    ///
+    /// let mut thin = ThinCev::new();
+    /// thin.push(2);
+    /// thin.push(1);
+    /// assert_eq!(thin.pop(), Some(1));
+    /// assert_eq!(thin, [2]);
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        let len = self.len();
+        if len == 0 {
+            None
+        } else {
+            unsafe {
+                let value = ptr::read(self.ptr());
+                self.set_len(len - 1);
+                Some(value)
+            }
+        }
+    }
+
+    /// Adds an element to the beginning of the `ThinCev` array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::ThinCev;
+    ///
+    /// let mut thin = ThinCev::new();
+    /// thin.push(3);
+    /// thin.push(2);
+    /// thin.push(1);
+    /// assert_eq!(thin, [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        let len = self.len();
+        if len == self.capacity() {
+            self.grow_one();
+        }
+
+        unsafe {
+            self.ptr().sub(1).write(value);
+            self.set_len(len + 1);
+        }
+    }
+
+    /// Removes and returns the element at position `index` within the `ThinCev` array,
+    /// shifting all elements before it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[track_caller]
+    pub fn remove(&mut self, index: usize) -> T {
+        #[cold]
+        #[inline(never)]
+        #[track_caller]
+        fn assert_failed(index: usize, len: usize) -> ! {
+            panic!("removal index (is {index}) should be < len (is {len})");
+        }
+
+        let len = self.len();
+        if index >= len {
+            assert_failed(index, len);
+        }
+
+        unsafe {
+            let p = self.ptr();
+            let ret = ptr::read(p.add(index));
+            ptr::copy(p, p.add(1), index);
+            self.set_len(len - 1);
+            ret
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        handle_reserve(self.try_reserve(additional));
+    }
+
+    /// Reduces the length of the `ThinCev` array to `len`,
+    /// by removing elements from the beginning of the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::{Cev, ThinCev};
+    ///
+    /// let mut thin = ThinCev::from(Cev::from(['T', 'r', 'u', 'n', 'c', 'a', 't', 'e']));
+    /// thin.truncate(4);
+    /// assert_eq!(thin, ['c', 'a', 't', 'e']);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        let old_len = self.len();
+        if len >= old_len {
+            return;
+        }
+
+        unsafe {
+            let s = ptr::slice_from_raw_parts_mut(self.ptr(), old_len - len);
+            self.set_len(len);
+            ptr::drop_in_place(s);
+        }
+    }
+
+    /// Tries to reserve capacity for at least `additional` more elements.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let (len, cap) = (self.len(), self.capacity());
+        if additional <= cap - len {
+            return Ok(());
+        }
+
+        let required = len
+            .checked_add(additional)
+            .ok_or(TryReserveErrorKind::CapacityOverflow)?;
+        self.try_grow_to(amortized_capacity(
+            &Doubling,
+            cap,
+            required,
+            mem::size_of::<T>(),
+        ))
+    }
+
+    /// Creates a new, empty `ThinCev` array with at least the specified capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut thin = ThinCev::new();
+        if capacity != 0 {
+            handle_reserve(thin.try_grow_to(capacity));
+        }
+        thin
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn grow_one(&mut self) {
+        self.reserve(1);
+    }
+
+    #[inline]
+    fn header(&self) -> &Header {
+        unsafe { self.ptr.as_ref() }
+    }
+
+    fn layout(cap: usize) -> Result<(Layout, usize), TryReserveError> {
+        Layout::array::<T>(cap)
+            .and_then(|array| Layout::new::<Header>().extend(array))
+            .map_err(|_| TryReserveErrorKind::CapacityOverflow.into())
+    }
+
+    /// Pointer to the first element, or one past the last element if empty.
+    #[inline]
+    fn ptr(&self) -> *mut T {
+        let Header { len, cap } = *self.header();
+        if cap == 0 {
+            return NonNull::dangling().as_ptr();
+        }
+
+        let offset = unsafe { Self::layout(0).unwrap_unchecked().1 };
+        unsafe {
+            let data = self.ptr.as_ptr().cast::<u8>().add(offset).cast::<T>();
+            data.add(cap - len)
+        }
+    }
+
+    /// Sets the length in the header. The `ThinCev` array must be allocated.
+    #[inline]
+    unsafe fn set_len(&mut self, len: usize) {
+        debug_assert!(self.capacity() != 0);
+
+        self.ptr.as_mut().len = len;
+    }
+
+    /// Allocates the header with room for `new_cap` elements, or grows the allocation
+    /// and moves the elements to its end.
+    fn try_grow_to(&mut self, new_cap: usize) -> Result<(), TryReserveError> {
+        let Header { len, cap } = *self.header();
+        let new_cap = if Self::IS_ZST { usize::MAX } else { new_cap };
+        debug_assert!(new_cap > cap);
+
+        let (new_layout, offset) = Self::layout(new_cap)?;
+        let alloc_err = |_| {
+            TryReserveError::from(TryReserveErrorKind::AllocError {
+                layout: new_layout,
+                non_exhaustive: (),
+            })
+        };
+
+        unsafe {
+            let new_ptr = if cap == 0 {
+                Global
+                    .allocate(new_layout)
+                    .map_err(alloc_err)?
+                    .cast::<Header>()
+            } else {
+                let (old_layout, _) = Self::layout(cap).unwrap_unchecked();
+                let new_ptr = Global
+                    .grow(self.ptr.cast::<u8>(), old_layout, new_layout)
+                    .map_err(alloc_err)?
+                    .cast::<Header>();
+                let data = new_ptr.as_ptr().cast::<u8>().add(offset).cast::<T>();
+                ptr::copy(data.add(cap - len), data.add(new_cap - len), len);
+                new_ptr
+            };
+
+            new_ptr.as_ptr().write(Header { len, cap: new_cap });
+            self.ptr = new_ptr;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Drop for ThinCev<T> {
+    fn drop(&mut self) {
+        let cap = self.capacity();
+        if cap != 0 {
+            unsafe {
+                ptr::drop_in_place(self.as_mut_slice());
+                let (layout, _) = Self::layout(cap).unwrap_unchecked();
+                Global.deallocate(self.ptr.cast::<u8>(), layout);
+            }
+        }
+    }
+}
+
+impl_slice_traits! { [] ThinCev<T> }
+
+impl_slice_eq! { [] ThinCev<T>, ThinCev<U> }
+impl_slice_eq! { [] ThinCev<T>, [U] }
+impl_slice_eq! { [] ThinCev<T>, &[U] }
+impl_slice_eq! { [const N: usize] ThinCev<T>, [U; N] }
+
+impl<T: Clone> Clone for ThinCev<T> {
+    fn clone(&self) -> Self {
+        let mut thin = ThinCev::with_capacity(self.len());
+        for elem in self.iter().rev() {
+            thin.push(elem.clone());
+        }
+        thin
+    }
+}
+
+impl<T> Default for ThinCev<T> {
+    fn default() -> Self {
+        ThinCev::new()
+    }
+}
+
+impl<T> From<Cev<T>> for ThinCev<T> {
+    /// Moves the elements into a new allocation with room for the header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::{Cev, ThinCev};
+    ///
+    /// let thin = ThinCev::from(Cev::from([1, 2, 3]));
+    /// assert_eq!(thin, [1, 2, 3]);
+    /// assert_eq!(thin.capacity(), 3);
+    /// ```
+    fn from(mut cev: Cev<T>) -> ThinCev<T> {
+        let len = cev.len();
+        let mut thin = ThinCev::<T>::with_capacity(len);
+
+        if len != 0 {
+            unsafe {
+                ptr::copy_nonoverlapping(cev.as_ptr(), thin.ptr().sub(len), len);
+                cev.set_len(0);
+                thin.set_len(len);
+            }
+        }
+        thin
+    }
+}
+
+impl<T> From<ThinCev<T>> for Cev<T> {
+    /// Moves the elements into a new `Cev` array allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cev::{Cev, ThinCev};
+    ///
+    /// let mut thin = ThinCev::new();
+    /// thin.push(2);
+    /// thin.push(1);
+    /// let mut cev = Cev::from(thin);
+    /// cev.push(0);
+    /// assert_eq!(cev, [0, 1, 2]);
+    /// ```
+    fn from(mut thin: ThinCev<T>) -> Cev<T> {
+        let len = thin.len();
+        let mut cev = Cev::<T>::with_capacity(len);
+
+        if len != 0 {
+            unsafe {
+                ptr::copy_nonoverlapping(thin.ptr(), cev.as_mut_ptr().sub(len), len);
+                thin.set_len(0);
+                cev.set_len(len);
+            }
+        }
+        cev
+    }
+}
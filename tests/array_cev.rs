@@ -0,0 +1,110 @@
+use cev::{ArrayCev, CapacityError, Cev};
+use std::rc::Rc;
+
+#[test]
+fn test_push_pop() {
+    let mut array: ArrayCev<i32, 4> = ArrayCev::new();
+    assert_eq!(array.pop(), None);
+    for i in 0..4 {
+        assert!(array.push(i).is_ok());
+    }
+    assert!(array.is_full());
+    assert_eq!(array.remaining_capacity(), 0);
+    assert_eq!(array, [3, 2, 1, 0]);
+    assert_eq!(array.push(4), Err(CapacityError::new(4)));
+
+    for i in (0..4).rev() {
+        assert_eq!(array.pop(), Some(i));
+    }
+    assert!(array.is_empty());
+    assert_eq!(array.remaining_capacity(), 4);
+}
+
+#[test]
+fn test_try_insert_remove() {
+    let mut array: ArrayCev<_, 4> = ArrayCev::new();
+    array.try_insert(0, 'c').unwrap();
+    array.try_insert(0, 'a').unwrap();
+    array.try_insert(1, 'b').unwrap();
+    array.try_insert(3, 'd').unwrap();
+    assert_eq!(array, ['a', 'b', 'c', 'd']);
+    assert_eq!(array.try_insert(2, 'x').unwrap_err().element(), 'x');
+
+    assert_eq!(array.remove(2), 'c');
+    assert_eq!(array.remove(0), 'a');
+    assert_eq!(array, ['b', 'd']);
+}
+
+#[test]
+#[should_panic(expected = "insertion index (is 2) should be <= len (is 1)")]
+fn test_try_insert_out_of_bounds() {
+    let mut array: ArrayCev<_, 4> = ArrayCev::new();
+    array.push(1).unwrap();
+    let _ = array.try_insert(2, 0);
+}
+
+#[test]
+fn test_truncate_drop() {
+    let rc = Rc::new(());
+    let mut array: ArrayCev<_, 8> = ArrayCev::new();
+    for _ in 0..6 {
+        array.push(Rc::clone(&rc)).unwrap();
+    }
+    array.truncate(2);
+    assert_eq!(array.len(), 2);
+    assert_eq!(Rc::strong_count(&rc), 3);
+
+    let err = array.clone().into_inner().unwrap_err();
+    assert_eq!(err.len(), 2);
+    assert_eq!(Rc::strong_count(&rc), 5);
+    drop(err);
+    drop(array);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_into_inner() {
+    let array = ArrayCev::from([String::from("a"), String::from("b")]);
+    assert!(array.is_full());
+    let inner = array.into_inner().unwrap();
+    assert_eq!(inner, ["a", "b"]);
+
+    let mut array: ArrayCev<u8, 0> = ArrayCev::new();
+    assert_eq!(array.push(1), Err(CapacityError::new(1)));
+    assert_eq!(array.into_inner(), Ok([]));
+}
+
+#[test]
+fn test_cev_conversions() {
+    let mut array: ArrayCev<_, 4> = ArrayCev::new();
+    array.push(String::from("b")).unwrap();
+    array.push(String::from("a")).unwrap();
+    let mut cev = Cev::from(array);
+    assert_eq!(cev, ["a", "b"]);
+    cev.push(String::from("_"));
+
+    let array: ArrayCev<_, 4> = ArrayCev::try_from(cev).unwrap();
+    assert_eq!(array, ["_", "a", "b"]);
+
+    let cev = Cev::from([1, 2, 3]);
+    let cev = ArrayCev::<_, 2>::try_from(cev).unwrap_err();
+    assert_eq!(cev, [1, 2, 3]);
+
+    let cev: Cev<u8> = ArrayCev::<u8, 4>::new().into_cev();
+    assert_eq!(cev.capacity(), 0);
+}
+
+#[test]
+fn test_capacity_error() {
+    let err = CapacityError::new(1);
+    assert_eq!(err.to_string(), "insufficient capacity");
+    assert_eq!(format!("{err:?}"), "CapacityError: insufficient capacity");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_capacity_error_std_error() {
+    let err: Box<dyn std::error::Error> = Box::new(CapacityError::new(1));
+    assert_eq!(err.to_string(), "insufficient capacity");
+    assert!(err.source().is_none());
+}
@@ -1,6 +1,9 @@
-use cev::Cev;
+use cev::{
+    Allocator, Cev, FixedIncrement, GrowthPolicy, OneAndHalf, PageRounded, TryReserveErrorKind,
+};
 use core::fmt::Debug;
 use core::mem::size_of;
+use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::rc::Rc;
 use std::ptr::NonNull;
 //use core::cell::Cell;
@@ -85,9 +88,33 @@ fn test_clone() {
     assert_eq!(c, c.clone());
 }
 
+#[test]
+fn test_clone_panic() {
+    struct P(Rc<()>, bool);
+
+    impl Clone for P {
+        fn clone(&self) -> Self {
+            if self.1 {
+                panic!("panic in `clone`");
+            }
+            P(Rc::clone(&self.0), false)
+        }
+    }
+
+    let rc = Rc::new(());
+    let cev = Cev::from([false, true, false, false].map(|p| P(Rc::clone(&rc), p)));
+
+    assert!(catch_unwind(AssertUnwindSafe(|| cev.clone())).is_err());
+
+    assert_eq!(Rc::strong_count(&rc), 5);
+    drop(cev);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
 #[test]
 fn test_small_vec_struct() {
-    assert_eq!(size_of::<Cev<u8>>(), size_of::<usize>() * 4);
+    assert_eq!(size_of::<Cev<u8>>(), size_of::<usize>() * 3);
+    assert_eq!(size_of::<Option<Cev<u8>>>(), size_of::<Cev<u8>>());
 }
 
 #[test]
@@ -125,7 +152,7 @@ fn test_indexing() {
     let mut x: usize = 0;
     assert_eq!(v[x], 10);
     assert_eq!(v[x + 1], 20);
-    x = x + 1;
+    x += 1;
     assert_eq!(v[x], 20);
     assert_eq!(v[x - 1], 10);
 }
@@ -166,14 +193,12 @@ fn test_from_cev() {
 
 }
 
-fn test_ptr_pos<T>(cev: &Cev<T>) -> bool {
+fn test_ptr_pos<T, A: Allocator, G: GrowthPolicy>(cev: &Cev<T, A, G>) -> bool {
     if cev.capacity() == usize::MAX {
         cev.as_ptr() == NonNull::<T>::dangling().as_ptr()
     } else if cev.capacity() == 0 {
         //cev.as_ptr() == cev.raw_ptr()
         cev.as_ptr() == NonNull::<T>::dangling().as_ptr()
-    } else if cev.len() == 0 {
-        unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) == ((cev.capacity() - 1) as isize) }
     } else {
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) == ((cev.capacity() - cev.len()) as isize) }
     }
@@ -317,6 +342,7 @@ fn test_slice_out_of_bounds_2() {
 
 #[test]
 #[should_panic]
+#[allow(clippy::reversed_empty_ranges)]
 fn test_slice_out_of_bounds_3() {
     let x = Cev::from([1, 2, 3, 4, 5]);
     let _ = &x[!0..4];
@@ -331,6 +357,7 @@ fn test_slice_out_of_bounds_4() {
 
 #[test]
 #[should_panic]
+#[allow(clippy::reversed_empty_ranges)]
 fn test_slice_out_of_bounds_5() {
     let x = Cev::from([1, 2, 3, 4, 5]);
     let _ = &x[3..2];
@@ -346,7 +373,7 @@ fn test_pop() {
     let mut cev = Cev::<u8>::from([253]);
     assert_eq!(cev.capacity(), 1);
     assert_eq!(cev.pop(), Some(253));
-    assert_eq!(unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) }, (cev.capacity() - 1) as isize);
+    assert_eq!(unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) }, cev.capacity() as isize);
 }
 
 #[test]
@@ -601,7 +628,7 @@ fn vec_macro_repeating_null_raw_fat_pointer() {
 
     let vec = Cev::from([null_raw_dyn; 1]);
     dbg!(ptr_metadata(vec[0]));
-    assert!(vec[0] == null_raw_dyn);
+    assert!(std::ptr::addr_eq(vec[0], null_raw_dyn));
 
     fn ptr_metadata(ptr: *mut dyn Fn()) -> *mut () {
         unsafe { std::mem::transmute::<*mut dyn Fn(), DynRepr>(ptr).vtable }
@@ -634,6 +661,95 @@ fn test_from_iter_specialization_head_tail_drop() {
     assert_eq!(sink.len(), 1);
 }
 
+#[test]
+fn test_from_iter_exact_size() {
+    let cev: Cev<_> = (0..100).map(|x| x.to_string()).collect();
+    assert_eq!(cev.len(), 100);
+    assert_eq!(cev.capacity(), 100);
+    assert!(cev.iter().map(|x| x.parse::<i32>().unwrap()).eq(0..100));
+    assert!(test_ptr_pos(&cev));
+
+    let cev: Cev<u8> = core::iter::empty().collect();
+    assert_eq!(cev.capacity(), 0);
+
+    let cev: Cev<()> = core::iter::repeat_n((), 5).collect();
+    assert_eq!(cev.len(), 5);
+}
+
+#[test]
+fn test_from_iter_unknown_size() {
+    let cev: Cev<_> = (0..100).filter(|x| x % 2 == 0).collect();
+    assert_eq!(cev.len(), 50);
+    assert!(cev.iter().copied().eq((0..100).step_by(2)));
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_from_iter_wrong_size_hint() {
+    struct Hint<I>(I, usize);
+
+    impl<I: Iterator> Iterator for Hint<I> {
+        type Item = I::Item;
+
+        fn next(&mut self) -> Option<I::Item> {
+            self.0.next()
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.1, Some(self.1))
+        }
+    }
+
+    let cev: Cev<_> = Hint(0..3, 6).collect();
+    assert_eq!(cev, [0, 1, 2]);
+    assert!(test_ptr_pos(&cev));
+
+    let cev: Cev<_> = Hint(0..6, 3).collect();
+    assert_eq!(cev, [0, 1, 2, 3, 4, 5]);
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev = Cev::from([9]);
+    cev.extend_front_ordered(Hint(0..2, 4));
+    assert_eq!(cev, [0, 1, 9]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_from_iter_panic() {
+    let rc = Rc::new(());
+    catch_unwind(AssertUnwindSafe(|| {
+        (0..5)
+            .map(|x| if x == 3 { panic!("iterator panic") } else { Rc::clone(&rc) })
+            .collect::<Cev<_>>()
+    }))
+    .unwrap_err();
+    assert_eq!(Rc::strong_count(&rc), 1);
+
+    catch_unwind(AssertUnwindSafe(|| {
+        (0..50)
+            .filter(|x| x % 2 == 0)
+            .map(|x| if x == 40 { panic!("iterator panic") } else { Rc::clone(&rc) })
+            .collect::<Cev<_>>()
+    }))
+    .unwrap_err();
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_from_iter_rev() {
+    let cev = Cev::from_iter_rev(0..5);
+    assert_eq!(cev, [4, 3, 2, 1, 0]);
+    assert_eq!(cev.capacity(), 5);
+    assert!(test_ptr_pos(&cev));
+
+    let cev = Cev::from_iter_rev((0..10).filter(|x| x % 3 == 0).rev());
+    assert_eq!(cev, [0, 3, 6, 9]);
+    assert!(test_ptr_pos(&cev));
+
+    let cev: Cev<u8> = Cev::from_iter_rev(None);
+    assert_eq!(cev.capacity(), 0);
+}
+
 #[test]
 fn test_into_iter_clone() {
     fn iter_equal<I: Iterator<Item = i32>>(it: I, slice: &[i32]) {
@@ -720,6 +836,7 @@ fn test_cev_truncate_zst() {
 #[test]
 fn test_cev_truncate_drop() {
     static mut DROPS: u32 = 0;
+    #[allow(dead_code)]
     struct Elem(i32);
     impl Drop for Elem {
         fn drop(&mut self) {
@@ -783,7 +900,7 @@ fn test_reserve_ptr() {
     assert!(reserve.capacity() >= 1);
     assert_eq!(
         unsafe { reserve.as_ptr().offset_from(reserve.raw_ptr()) as usize },
-        reserve.capacity() - 1
+        reserve.capacity()
     );
     reserve.push(1);
     assert_eq!(
@@ -824,7 +941,7 @@ fn test_append() {
     assert_eq!(vec2, []);
     assert_eq!(
         unsafe { vec2.as_ptr().offset_from(vec2.raw_ptr()) as usize },
-        vec2.capacity() - vec2.len() - 1
+        vec2.capacity() - vec2.len()
     );
 
     let mut cev = Cev::from([3, 4, 5, 6, 7, 8, 9]);
@@ -882,132 +999,1477 @@ fn test_insert_panic() {
 }
 
 #[test]
-fn test_into_iter_debug() {
-    let cev = Cev::from(['a', 'b', 'c']);
-    let into_iter = cev.into_iter();
-    let debug = format!("{into_iter:?}");
-    assert_eq!(debug, "IntoIter(['a', 'b', 'c'])");
+fn test_try_reserve() {
+    let mut cev: Cev<u8> = Cev::new();
+    assert_eq!(cev.try_reserve(0), Ok(()));
+    assert_eq!(cev.capacity(), 0);
+
+    assert_eq!(cev.try_reserve(1), Ok(()));
+    assert!(cev.capacity() >= 1);
+    assert!(test_ptr_pos(&cev));
+
+    cev.push(1);
+    cev.push(2);
+    assert_eq!(cev.try_reserve_exact(10), Ok(()));
+    assert_eq!(cev.capacity(), 12);
+    assert!(test_ptr_pos(&cev));
+    assert_eq!(cev, [2, 1]);
+
+    assert_eq!(
+        cev.try_reserve(usize::MAX).map_err(|e| e.kind()),
+        Err(TryReserveErrorKind::CapacityOverflow)
+    );
+    assert_eq!(
+        cev.try_reserve_exact(usize::MAX - 1).map_err(|e| e.kind()),
+        Err(TryReserveErrorKind::CapacityOverflow)
+    );
+    assert!(matches!(
+        cev.try_reserve_exact(isize::MAX as usize - 2).map_err(|e| e.kind()),
+        Err(TryReserveErrorKind::AllocError { .. })
+    ));
+    assert_eq!(cev.capacity(), 12);
+    assert!(test_ptr_pos(&cev));
+    assert_eq!(cev, [2, 1]);
+
+    let mut cev: Cev<()> = Cev::new();
+    assert_eq!(cev.try_reserve(usize::MAX), Ok(()));
+    cev.push(());
+    assert_eq!(
+        cev.try_reserve(usize::MAX).map_err(|e| e.kind()),
+        Err(TryReserveErrorKind::CapacityOverflow)
+    );
 }
 
 #[test]
-fn test_cev_clear_drop() {
+fn test_try_with_capacity() {
+    let cev = Cev::<u32>::try_with_capacity(0).unwrap();
+    assert_eq!(cev.capacity(), 0);
+    assert!(test_ptr_pos(&cev));
+
+    let cev = Cev::<u32>::try_with_capacity(10).unwrap();
+    assert_eq!(cev.capacity(), 10);
+    assert!(test_ptr_pos(&cev));
+
+    let cev = Cev::<()>::try_with_capacity(usize::MAX).unwrap();
+    assert_eq!(cev.capacity(), usize::MAX);
+
+    assert_eq!(
+        Cev::<u32>::try_with_capacity(usize::MAX).map_err(|e| e.kind()),
+        Err(TryReserveErrorKind::CapacityOverflow)
+    );
+    assert!(matches!(
+        Cev::<u8>::try_with_capacity(isize::MAX as usize).map_err(|e| e.kind()),
+        Err(TryReserveErrorKind::AllocError { .. })
+    ));
+}
+
+#[test]
+fn test_try_push_insert_append() {
+    let mut cev = Cev::new();
+    for i in 0..10 {
+        assert_eq!(cev.try_push(i), Ok(()));
+        assert!(test_ptr_pos(&cev));
+    }
+    assert_eq!(cev, [9, 8, 7, 6, 5, 4, 3, 2, 1, 0]);
+
+    let mut cev = Cev::from([1, 2, 3]);
+    assert_eq!(cev.try_insert(0, 0), Ok(()));
+    assert_eq!(cev.try_insert(4, 4), Ok(()));
+    assert_eq!(cev.try_insert(2, 9), Ok(()));
+    assert_eq!(cev, [0, 1, 9, 2, 3, 4]);
+    assert!(test_ptr_pos(&cev));
+
+    let mut other = Cev::from([-2, -1]);
+    assert_eq!(cev.try_append(&mut other), Ok(()));
+    assert_eq!(cev, [-2, -1, 0, 1, 9, 2, 3, 4]);
+    assert_eq!(other, []);
+    assert!(test_ptr_pos(&cev));
+    assert!(test_ptr_pos(&other));
+}
+
+#[test]
+#[should_panic]
+fn test_try_insert_panic() {
+    let mut ist = Cev::from([1, 2, 3]);
+    let _ = ist.try_insert(5, 5);
+}
+
+#[test]
+fn test_drain_items() {
+    let mut cev = Cev::from([1, 2, 3]);
+    let mut cev2 = Cev::new();
+    for i in cev.drain(..) {
+        cev2.push(i);
+    }
+    assert_eq!(cev, []);
+    assert_eq!(cev2, [3, 2, 1]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_drain_items_reverse() {
+    let mut cev = Cev::from([1, 2, 3]);
+    let mut cev2 = Cev::new();
+    for i in cev.drain(..).rev() {
+        cev2.push(i);
+    }
+    assert_eq!(cev, []);
+    assert_eq!(cev2, [1, 2, 3]);
+}
+
+#[test]
+fn test_drain_range() {
+    let mut cev = Cev::from([1, 2, 3, 4, 5]);
+    for _ in cev.drain(4..) {}
+    assert_eq!(cev, [1, 2, 3, 4]);
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev: Cev<_> = (1..=5).map(|x| x.to_string()).collect();
+    for _ in cev.drain(1..4) {}
+    assert_eq!(cev, ["1".to_string(), "5".to_string()]);
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev: Cev<_> = (1..=5).map(|x| x.to_string()).collect();
+    for _ in cev.drain(1..4).rev() {}
+    assert_eq!(cev, ["1".to_string(), "5".to_string()]);
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev: Cev<_> = Cev::from([(); 5]);
+    for _ in cev.drain(1..4).rev() {}
+    assert_eq!(cev, [(), ()]);
+
+    let mut cev = Cev::from([1, 2, 3, 4, 5]);
+    let drained: Vec<_> = cev.drain(..2).collect();
+    assert_eq!(drained, [1, 2]);
+    assert_eq!(cev, [3, 4, 5]);
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev = Cev::from([1, 2, 3, 4, 5]);
+    assert_eq!(cev.drain(2..2).count(), 0);
+    assert_eq!(cev, [1, 2, 3, 4, 5]);
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev: Cev<u8> = Cev::new();
+    assert_eq!(cev.drain(..).count(), 0);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_drain_inclusive_range() {
+    let mut cev = Cev::from(['a', 'b', 'c', 'd', 'e']);
+    for _ in cev.drain(1..=3) {}
+    assert_eq!(cev, ['a', 'e']);
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev: Cev<_> = (0..=5).map(|x| x.to_string()).collect();
+    let drained: Vec<_> = cev.drain(0..=2).collect();
+    assert_eq!(drained, ["0", "1", "2"]);
+    assert_eq!(cev, ["3", "4", "5"]);
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev: Cev<_> = (0..=5).map(|x| x.to_string()).collect();
+    let drained: Vec<_> = cev.drain(3..=5).collect();
+    assert_eq!(drained, ["3", "4", "5"]);
+    assert_eq!(cev, ["0", "1", "2"]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+#[should_panic]
+fn test_drain_index_overflow() {
+    let mut cev = Cev::from([1, 2, 3]);
+    cev.drain(0..=usize::MAX);
+}
+
+#[test]
+#[should_panic]
+fn test_drain_out_of_bounds() {
+    let mut cev = Cev::from([1, 2, 3, 4, 5]);
+    cev.drain(5..6);
+}
+
+#[test]
+fn test_drain_as_slice_keep_rest() {
+    let mut cev = Cev::from(['a', 'b', 'c', 'd', 'e', 'f']);
+    let mut drain = cev.drain(1..5);
+    assert_eq!(drain.as_slice(), ['b', 'c', 'd', 'e']);
+    assert_eq!(drain.next(), Some('b'));
+    assert_eq!(drain.next_back(), Some('e'));
+    assert_eq!(drain.len(), 2);
+    assert_eq!(drain.as_slice(), ['c', 'd']);
+    drain.keep_rest();
+    assert_eq!(cev, ['a', 'c', 'd', 'f']);
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev: Cev<_> = (0..6).map(|x| x.to_string()).collect();
+    let mut drain = cev.drain(..4);
+    assert_eq!(drain.next().as_deref(), Some("0"));
+    drain.keep_rest();
+    assert_eq!(cev, ["1", "2", "3", "4", "5"]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_drain_forget() {
+    let mut cev = Cev::from([1, 2, 3, 4, 5]);
+    core::mem::forget(cev.drain(1..3));
+    assert_eq!(cev, [4, 5]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_drain_drop_panic_leak() {
     static mut DROPS: u32 = 0;
-    struct Elem(i32);
-    impl Drop for Elem {
+
+    struct D(bool);
+
+    impl Drop for D {
         fn drop(&mut self) {
             unsafe {
                 DROPS += 1;
             }
+
+            if self.0 {
+                panic!("panic in `drop`");
+            }
         }
     }
 
-    let mut cev = Cev::with_capacity(5);
-    let mut cev_three = Cev::from([Elem(1), Elem(2), Elem(3)]);
-    let mut cev_five = Cev::from([Elem(1), Elem(2), Elem(3), Elem(4), Elem(5)]);
-    assert_eq!(unsafe { DROPS }, 0);
-    cev.append(&mut cev_three);
-    cev.clear();
-    assert_eq!(unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) }, (cev.capacity() - 1) as isize);
-    assert_eq!(unsafe { DROPS }, 3);
-    unsafe { DROPS = 0 };
-    cev.append(&mut cev_five);
-    cev.clear();
-    assert_eq!(unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) }, (cev.capacity() - 1) as isize);
+    let mut cev = Cev::from([
+        D(false),
+        D(false),
+        D(false),
+        D(false),
+        D(false),
+        D(true),
+        D(false),
+    ]);
+
+    catch_unwind(AssertUnwindSafe(|| {
+        cev.drain(2..7);
+    }))
+    .ok();
+
     assert_eq!(unsafe { DROPS }, 5);
+    assert_eq!(cev.len(), 2);
+    assert!(test_ptr_pos(&cev));
+
+    drop(cev);
+    assert_eq!(unsafe { DROPS }, 7);
 }
 
 #[test]
-fn test_clear() {
-    let mut cev: Cev<u8> = Cev::new();
-    assert_eq!(cev.as_ptr(), cev.raw_ptr());
-    cev.clear();
-    assert_eq!(cev.as_ptr(), cev.raw_ptr());
-    assert_eq!(cev, Cev::new());
+fn test_retain() {
+    let mut cev = Cev::from([1, 2, 3, 4]);
+    cev.retain(|&x| x % 2 == 0);
+    assert_eq!(cev, [2, 4]);
+    assert!(test_ptr_pos(&cev));
 
-    let mut cev: Cev<u8> = Cev::with_capacity(0);
-    assert_eq!(cev.as_ptr(), cev.raw_ptr());
-    cev.clear();
-    assert_eq!(cev.as_ptr(), cev.raw_ptr());
-    assert_eq!(cev, Cev::new());
+    let mut cev: Cev<_> = (0..10).map(|x| x.to_string()).collect();
+    cev.retain(|x| x.parse::<u8>().unwrap() >= 7);
+    assert_eq!(cev, ["7", "8", "9"]);
+    assert!(test_ptr_pos(&cev));
 
-    let mut cev: Cev<u8> = Cev::from([1]);
-    assert_eq!(cev.as_ptr(), cev.raw_ptr());
-    cev.clear();
-    assert_eq!(cev, []);
-    assert_eq!(cev.as_ptr(), cev.raw_ptr());
-    cev.reserve(8);
-    assert_eq!(
-        unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - 1) as isize
-    );
-    assert_eq!(cev, []);
-    cev.clear();
-    assert_eq!(
-        unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - 1) as isize
-    );
-    assert_eq!(cev, []);
-    cev.push(4);
-    cev.push(3);
-    cev.push(2);
-    cev.push(1);
-    cev.clear();
-    assert_eq!(
-        unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - 1) as isize
-    );
-    assert_eq!(cev, []);
+    cev.retain(|_| false);
+    assert_eq!(cev, Cev::<String>::new());
+    assert!(test_ptr_pos(&cev));
 
-    let mut cev: Cev<()> = Cev::with_capacity(0);
-    cev.clear();
-    assert_eq!(cev, []);
-    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+    let mut cev = Cev::from([(), (), ()]);
+    let mut keep = false;
+    cev.retain(|_| {
+        keep = !keep;
+        keep
+    });
+    assert_eq!(cev, [(), ()]);
 
-    let mut cev: Cev<()> = Cev::with_capacity(1);
-    cev.clear();
-    assert_eq!(cev, []);
-    assert_eq!(cev.as_ptr(), cev.raw_ptr());
-    unsafe {
-        cev.set_len_ptr(0);
-    };
-    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+    let mut cev: Cev<u8> = Cev::new();
+    cev.retain(|_| false);
+    assert!(test_ptr_pos(&cev));
+}
 
-    let mut cev: Cev<()> = Cev::with_capacity(10);
-    assert_eq!(cev.as_ptr(), cev.raw_ptr());
-    cev.clear();
-    assert_eq!(cev, []);
-    unsafe {
-        cev.set_len_ptr(0);
-    };
-    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+#[test]
+fn test_retain_order() {
+    let mut cev = Cev::from([1, 2, 3, 4, 5]);
+    let mut visited = Vec::new();
+    cev.retain(|&x| {
+        visited.push(x);
+        true
+    });
+    assert_eq!(visited, [5, 4, 3, 2, 1]);
+    assert_eq!(cev, [1, 2, 3, 4, 5]);
 }
 
 #[test]
-fn test_set_len_ptr() {
-    let mut cev: Cev<String> = Cev::new();
-    assert_eq!(cev.as_ptr(), cev.raw_ptr());
-    unsafe {
-        cev.set_len_ptr(0);
-    };
-    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+fn test_retain_mut() {
+    let mut cev = Cev::from([9, 9, 9, 9]);
+    let mut i = 0;
+    cev.retain_mut(|x| {
+        i += 1;
+        *x += i;
+        i % 2 == 0
+    });
+    assert_eq!(cev, [13, 11]);
+    assert!(test_ptr_pos(&cev));
+}
 
-    let mut cev: Cev<u8> = Cev::from([1]);
-    assert_eq!(cev.as_ptr(), cev.raw_ptr());
-    unsafe {
-        cev.set_len_ptr(0);
-    };
-    assert_eq!(cev.as_ptr(), cev.raw_ptr());
-    cev.reserve(8);
-    cev.push(4);
-    cev.push(3);
-    cev.push(2);
-    cev.push(1);
-    unsafe {
-        cev.set_len_ptr(0);
-    };
-    assert_eq!(
+#[test]
+fn test_retain_predicate_panic() {
+    let mut cev: Cev<_> = (0..8).map(Rc::new).collect();
+    let check = cev.clone();
+
+    catch_unwind(AssertUnwindSafe(|| {
+        cev.retain(|x| match **x {
+            3 => panic!("predicate panic"),
+            x => x % 2 == 0,
+        })
+    }))
+    .unwrap_err();
+
+    assert_eq!(cev.len(), 6);
+    assert!(test_ptr_pos(&cev));
+    assert_eq!(cev, [0, 1, 2, 3, 4, 6].map(Rc::new));
+    assert_eq!(Rc::strong_count(&check[5]), 1);
+    assert_eq!(Rc::strong_count(&check[4]), 2);
+}
+
+#[test]
+fn test_retain_drop_panic() {
+    struct Droppable(u8, bool);
+
+    impl Drop for Droppable {
+        fn drop(&mut self) {
+            if self.1 {
+                panic!("drop panic");
+            }
+        }
+    }
+
+    let mut cev: Cev<_> = (0..6).map(|i| Droppable(i, i == 2)).collect();
+    catch_unwind(AssertUnwindSafe(|| cev.retain(|x| x.0 % 2 == 1))).unwrap_err();
+
+    assert_eq!(cev.iter().map(|x| x.0).collect::<Vec<_>>(), [0, 1, 3, 5]);
+    assert!(test_ptr_pos(&cev));
+    cev[0].1 = false;
+}
+
+#[test]
+fn test_dedup() {
+    fn case(a: Cev<i32>, b: Cev<i32>) {
+        let mut cev = a;
+        cev.dedup();
+        assert_eq!(cev, b);
+        assert!(test_ptr_pos(&cev));
+    }
+    case(Cev::new(), Cev::new());
+    case(Cev::from([1]), Cev::from([1]));
+    case(Cev::from([1, 1]), Cev::from([1]));
+    case(Cev::from([1, 2, 3]), Cev::from([1, 2, 3]));
+    case(Cev::from([1, 1, 2, 3]), Cev::from([1, 2, 3]));
+    case(Cev::from([1, 2, 2, 3]), Cev::from([1, 2, 3]));
+    case(Cev::from([1, 2, 3, 3]), Cev::from([1, 2, 3]));
+    case(Cev::from([1, 1, 2, 2, 2, 3, 3]), Cev::from([1, 2, 3]));
+}
+
+#[test]
+fn test_dedup_by_key() {
+    fn case(a: Cev<i32>, b: Cev<i32>) {
+        let mut cev = a;
+        cev.dedup_by_key(|i| *i / 10);
+        assert_eq!(cev, b);
+        assert!(test_ptr_pos(&cev));
+    }
+    case(Cev::new(), Cev::new());
+    case(Cev::from([10]), Cev::from([10]));
+    case(Cev::from([10, 11]), Cev::from([10]));
+    case(Cev::from([10, 20, 30]), Cev::from([10, 20, 30]));
+    case(Cev::from([10, 11, 20, 30]), Cev::from([10, 20, 30]));
+    case(Cev::from([10, 20, 21, 30]), Cev::from([10, 20, 30]));
+    case(Cev::from([10, 20, 30, 31]), Cev::from([10, 20, 30]));
+    case(Cev::from([10, 11, 20, 21, 22, 30, 31]), Cev::from([10, 20, 30]));
+}
+
+#[test]
+fn test_dedup_by() {
+    let mut cev = Cev::from(["foo", "bar", "Bar", "baz", "bar"]);
+    cev.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    assert_eq!(cev, ["foo", "bar", "baz", "bar"]);
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev = Cev::from([("foo", 1), ("foo", 2), ("bar", 3), ("bar", 4), ("bar", 5)]);
+    cev.dedup_by(|a, b| {
+        a.0 == b.0 && {
+            b.1 += a.1;
+            true
+        }
+    });
+    assert_eq!(cev, [("foo", 3), ("bar", 12)]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_dedup_keeps_first() {
+    let mut cev = Cev::from([String::from("a"), String::from("A"), String::from("b"), String::from("B"), String::from("b")]);
+    cev.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    assert_eq!(cev, ["a", "b"]);
+    assert!(test_ptr_pos(&cev));
+
+    let mut vec = vec!["a", "A", "b", "B", "b"];
+    vec.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    assert_eq!(cev, vec);
+
+    let mut cev = Cev::from([(1, 'a'), (1, 'b'), (1, 'c'), (2, 'd')]);
+    cev.dedup_by_key(|t| t.0);
+    assert_eq!(cev, [(1, 'a'), (2, 'd')]);
+
+    let mut seen = Vec::new();
+    let mut cev = Cev::from(['a', 'b', 'c']);
+    cev.dedup_by(|a, b| {
+        seen.push((*a, *b));
+        true
+    });
+    assert_eq!(seen, [('b', 'a'), ('c', 'a')]);
+    assert_eq!(cev, ['a']);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_dedup_unique() {
+    let mut v0: Cev<Box<_>> = Cev::from([Box::new(1), Box::new(1), Box::new(2), Box::new(3)]);
+    v0.dedup();
+    let mut v1: Cev<Box<_>> = Cev::from([Box::new(1), Box::new(2), Box::new(2), Box::new(3)]);
+    v1.dedup();
+    let mut v2: Cev<Box<_>> = Cev::from([Box::new(1), Box::new(2), Box::new(3), Box::new(3)]);
+    v2.dedup();
+    assert_eq!(v0, [Box::new(1), Box::new(2), Box::new(3)]);
+    assert_eq!(v1, [Box::new(1), Box::new(2), Box::new(3)]);
+    assert_eq!(v2, [Box::new(1), Box::new(2), Box::new(3)]);
+}
+
+#[test]
+fn test_dedup_predicate_panic() {
+    fn dedup_panicking(dedup_by: impl FnOnce(&mut dyn FnMut(&mut Rc<i32>, &mut Rc<i32>) -> bool)) {
+        let mut calls = 0;
+        catch_unwind(AssertUnwindSafe(|| {
+            dedup_by(&mut |a, b| {
+                calls += 1;
+                if calls == 3 {
+                    panic!("predicate panic");
+                }
+                a == b
+            })
+        }))
+        .unwrap_err();
+    }
+
+    let mut cev: Cev<_> = [1, 1, 2, 2, 3, 3].map(Rc::new).into();
+    dedup_panicking(|f| cev.dedup_by(f));
+    let mut vec = [1, 1, 2, 2, 3, 3].map(Rc::new).to_vec();
+    dedup_panicking(|f| vec.dedup_by(f));
+
+    assert_eq!(cev, [1, 2, 2, 3, 3].map(Rc::new));
+    assert_eq!(cev, vec);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_remove() {
+    let mut cev: Cev<_> = (0..6).map(|x| x.to_string()).collect();
+    assert_eq!(cev.remove(3), "3");
+    assert_eq!(cev, ["0", "1", "2", "4", "5"]);
+    assert!(test_ptr_pos(&cev));
+    assert_eq!(cev.remove(0), "0");
+    assert_eq!(cev.remove(3), "5");
+    assert_eq!(cev, ["1", "2", "4"]);
+    assert!(test_ptr_pos(&cev));
+    assert_eq!(cev.remove(1), "2");
+    assert_eq!(cev.remove(1), "4");
+    assert_eq!(cev.remove(0), "1");
+    assert_eq!(cev, Cev::<String>::new());
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev = Cev::from([(), ()]);
+    cev.remove(1);
+    assert_eq!(cev, [()]);
+}
+
+#[test]
+#[should_panic]
+fn test_remove_fail() {
+    let mut cev = Cev::from([1]);
+    let _ = cev.remove(0);
+    let _ = cev.remove(0);
+}
+
+#[test]
+fn test_swap_remove() {
+    let mut cev: Cev<_> = (0..5).map(|x| x.to_string()).collect();
+    assert_eq!(cev.swap_remove(4), "4");
+    assert_eq!(cev, ["1", "2", "3", "0"]);
+    assert!(test_ptr_pos(&cev));
+    assert_eq!(cev.swap_remove(0), "1");
+    assert_eq!(cev, ["2", "3", "0"]);
+    assert!(test_ptr_pos(&cev));
+    assert_eq!(cev.swap_remove(1), "3");
+    assert_eq!(cev.swap_remove(1), "0");
+    assert_eq!(cev.swap_remove(0), "2");
+    assert_eq!(cev, Cev::<String>::new());
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+#[should_panic]
+fn test_swap_remove_empty() {
+    let mut cev = Cev::<i32>::new();
+    cev.swap_remove(0);
+}
+
+#[test]
+fn test_split_off() {
+    let mut cev = Cev::from([1, 2, 3, 4, 5, 6]);
+    let orig_ptr = cev.raw_ptr();
+    let orig_capacity = cev.capacity();
+    let split_off = cev.split_off(4);
+    assert_eq!(cev, [1, 2, 3, 4]);
+    assert_eq!(split_off, [5, 6]);
+    assert_eq!(cev.capacity(), orig_capacity);
+    assert_eq!(cev.raw_ptr(), orig_ptr);
+    assert!(test_ptr_pos(&cev));
+    assert!(test_ptr_pos(&split_off));
+
+    let mut cev: Cev<_> = (0..4).map(|x| x.to_string()).collect();
+    let split_off = cev.split_off(4);
+    assert_eq!(cev, ["0", "1", "2", "3"]);
+    assert_eq!(split_off, Cev::<String>::new());
+    assert!(test_ptr_pos(&cev));
+    assert!(test_ptr_pos(&split_off));
+
+    let split_off = cev.split_off(0);
+    assert_eq!(cev, Cev::<String>::new());
+    assert_eq!(cev.capacity(), split_off.capacity());
+    assert_eq!(split_off, ["0", "1", "2", "3"]);
+    assert!(test_ptr_pos(&cev));
+    assert!(test_ptr_pos(&split_off));
+}
+
+#[test]
+#[should_panic]
+fn test_split_off_fail() {
+    let mut cev = Cev::from([1, 2, 3]);
+    let _ = cev.split_off(4);
+}
+
+#[test]
+fn test_split_off_front() {
+    let mut cev = Cev::from([1, 2, 3, 4, 5, 6]);
+    let orig_ptr = cev.raw_ptr();
+    let orig_capacity = cev.capacity();
+    let front = cev.split_off_front(2);
+    assert_eq!(front, [1, 2]);
+    assert_eq!(cev, [3, 4, 5, 6]);
+    assert_eq!(cev.capacity(), orig_capacity);
+    assert_eq!(cev.raw_ptr(), orig_ptr);
+    assert!(test_ptr_pos(&cev));
+    assert!(test_ptr_pos(&front));
+
+    let front = cev.split_off_front(0);
+    assert_eq!(front, []);
+    assert_eq!(cev, [3, 4, 5, 6]);
+    assert!(test_ptr_pos(&front));
+
+    let front = cev.split_off_front(4);
+    assert_eq!(front, [3, 4, 5, 6]);
+    assert_eq!(cev, []);
+    assert_eq!(cev.capacity(), orig_capacity);
+    assert!(test_ptr_pos(&cev));
+    assert!(test_ptr_pos(&front));
+
+    let mut cev = Cev::from([(); 3]);
+    assert_eq!(cev.split_off_front(1), [()]);
+    assert_eq!(cev, [(), ()]);
+}
+
+#[test]
+#[should_panic]
+fn test_split_off_front_fail() {
+    let mut cev = Cev::from([1, 2, 3]);
+    let _ = cev.split_off_front(4);
+}
+
+#[test]
+fn test_extend() {
+    let mut cev = Cev::new();
+    let mut vec = Vec::new();
+
+    cev.extend(0..3);
+    vec.extend(0..3);
+    vec.reverse();
+    assert_eq!(cev, vec);
+    assert!(test_ptr_pos(&cev));
+
+    cev.extend(3..10);
+    assert_eq!(cev, [9, 8, 7, 6, 5, 4, 3, 2, 1, 0]);
+    assert!(test_ptr_pos(&cev));
+
+    let mut a = Cev::from([1, 2, 3]);
+    let b = Cev::from([4, 5, 6]);
+    a.extend(b.iter());
+    assert_eq!(a, [6, 5, 4, 1, 2, 3]);
+
+    let mut cev: Cev<u8> = Cev::new();
+    cev.extend((0..4).filter(|x| x % 2 == 0));
+    assert_eq!(cev, [2, 0]);
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev = Cev::new();
+    cev.extend(core::iter::repeat_n((), 3));
+    assert_eq!(cev, [(), (), ()]);
+}
+
+#[test]
+fn test_extend_ref() {
+    let mut cev = Cev::from([1, 2]);
+    cev.extend(&[3, 4, 5]);
+    assert_eq!(cev.len(), 5);
+    assert_eq!(cev, [5, 4, 3, 1, 2]);
+
+    let w = Cev::from([6, 7]);
+    cev.extend(&w);
+    assert_eq!(cev, [7, 6, 5, 4, 3, 1, 2]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_extend_front_ordered() {
+    let mut cev: Cev<String> = Cev::new();
+    cev.extend_front_ordered(["c".to_string(), "d".to_string()]);
+    assert_eq!(cev, ["c", "d"]);
+    assert!(test_ptr_pos(&cev));
+
+    cev.extend_front_ordered(["a", "b"].iter().map(|x| x.to_string()));
+    assert_eq!(cev, ["a", "b", "c", "d"]);
+    assert!(test_ptr_pos(&cev));
+
+    cev.extend_front_ordered(core::iter::empty());
+    assert_eq!(cev, ["a", "b", "c", "d"]);
+
+    let mut cev = Cev::from([10, 11]);
+    cev.extend_front_ordered((0..10).filter(|x| x % 3 == 0));
+    assert_eq!(cev, [0, 3, 6, 9, 10, 11]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_extend_front_ordered_panic() {
+    let mut cev = Cev::from([9]);
+    catch_unwind(AssertUnwindSafe(|| {
+        cev.extend_front_ordered((0..5).map(|x| if x == 3 { panic!("iterator panic") } else { x }))
+    }))
+    .unwrap_err();
+    assert_eq!(cev, [0, 1, 2, 9]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_prepend_slice() {
+    let mut cev: Cev<String> = Cev::new();
+    cev.prepend_slice(&["3".to_string()]);
+    cev.prepend_slice(&["1".to_string(), "2".to_string()]);
+    assert_eq!(cev, ["1", "2", "3"]);
+    assert!(test_ptr_pos(&cev));
+
+    cev.prepend_slice(&[]);
+    assert_eq!(cev, ["1", "2", "3"]);
+
+    let mut cev = Cev::with_capacity(4);
+    cev.prepend_slice(&[1, 2, 3, 4]);
+    assert_eq!(cev.capacity(), 4);
+    assert_eq!(cev, [1, 2, 3, 4]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_prepend_slice_clone_panic() {
+    #[derive(Debug, PartialEq)]
+    struct P(Rc<()>, bool);
+
+    impl Clone for P {
+        fn clone(&self) -> Self {
+            if self.1 {
+                panic!("panic in `clone`");
+            }
+            P(Rc::clone(&self.0), false)
+        }
+    }
+
+    let rc = Rc::new(());
+    let mut cev = Cev::from([P(Rc::clone(&rc), false)]);
+    let other = [false, true, false, false].map(|p| P(Rc::clone(&rc), p));
+
+    assert!(catch_unwind(AssertUnwindSafe(|| cev.prepend_slice(&other))).is_err());
+    assert_eq!(cev.len(), 3);
+    assert!(test_ptr_pos(&cev));
+    assert_eq!(Rc::strong_count(&rc), 8);
+
+    drop(cev);
+    drop(other);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_prepend_from_within() {
+    let mut cev = Cev::from(["a".to_string(), "b".to_string(), "c".to_string()]);
+    cev.prepend_from_within(1..);
+    assert_eq!(cev, ["b", "c", "a", "b", "c"]);
+    assert!(test_ptr_pos(&cev));
+
+    cev.prepend_from_within(..=0);
+    assert_eq!(cev, ["b", "b", "c", "a", "b", "c"]);
+
+    cev.prepend_from_within(2..2);
+    assert_eq!(cev, ["b", "b", "c", "a", "b", "c"]);
+
+    cev.prepend_from_within(..);
+    assert_eq!(cev.len(), 12);
+    assert_eq!(cev[..6], cev[6..]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+#[should_panic]
+fn test_prepend_from_within_out_of_bounds() {
+    let mut cev = Cev::from([1, 2, 3]);
+    cev.prepend_from_within(2..4);
+}
+
+#[test]
+fn test_resize() {
+    let mut cev = Cev::from(["c".to_string()]);
+    cev.resize(3, "x".to_string());
+    assert_eq!(cev, ["x", "x", "c"]);
+    assert!(test_ptr_pos(&cev));
+
+    cev.resize(3, "y".to_string());
+    assert_eq!(cev, ["x", "x", "c"]);
+
+    cev.resize(1, "y".to_string());
+    assert_eq!(cev, ["c"]);
+    assert!(test_ptr_pos(&cev));
+
+    cev.resize(0, "y".to_string());
+    assert_eq!(cev, Cev::<String>::new());
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev: Cev<u8> = Cev::with_capacity(8);
+    cev.resize(8, 7);
+    assert_eq!(cev.capacity(), 8);
+    assert_eq!(cev, [7; 8]);
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev: Cev<()> = Cev::new();
+    cev.resize(5, ());
+    assert_eq!(cev.len(), 5);
+}
+
+#[test]
+fn test_resize_with() {
+    let mut cev = Cev::from([0]);
+    let mut next = 0;
+    cev.resize_with(4, || {
+        next += 1;
+        next
+    });
+    assert_eq!(cev, [3, 2, 1, 0]);
+    assert!(test_ptr_pos(&cev));
+
+    cev.resize_with(2, || unreachable!());
+    assert_eq!(cev, [1, 0]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_resize_with_panic() {
+    let mut cev: Cev<String> = Cev::from(["z".to_string()]);
+    let mut calls = 0;
+    catch_unwind(AssertUnwindSafe(|| {
+        cev.resize_with(5, || {
+            calls += 1;
+            if calls == 3 {
+                panic!("closure panic");
+            }
+            calls.to_string()
+        })
+    }))
+    .unwrap_err();
+    assert_eq!(cev, ["2", "1", "z"]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_resize_default() {
+    let mut cev: Cev<String> = Cev::new();
+    cev.resize_default(2);
+    assert_eq!(cev, ["", ""]);
+    assert!(test_ptr_pos(&cev));
+
+    cev[1].push('a');
+    cev.resize_default(1);
+    assert_eq!(cev, ["a"]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_splice() {
+    let mut cev = Cev::from([1, 2, 3, 4, 5]);
+    let a = [10, 11, 12];
+    cev.splice(2..4, a);
+    assert_eq!(cev, [1, 2, 10, 11, 12, 5]);
+    assert!(test_ptr_pos(&cev));
+    cev.splice(1..3, Some(20));
+    assert_eq!(cev, [1, 20, 11, 12, 5]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_splice_inclusive_range() {
+    let mut cev = Cev::from([1, 2, 3, 4, 5]);
+    let a = [10, 11, 12];
+    let t1: Vec<_> = cev.splice(2..=3, a).collect();
+    assert_eq!(cev, [1, 2, 10, 11, 12, 5]);
+    assert_eq!(t1, [3, 4]);
+    let t2: Vec<_> = cev.splice(1..=2, Some(20)).collect();
+    assert_eq!(cev, [1, 20, 11, 12, 5]);
+    assert_eq!(t2, [2, 10]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+#[should_panic]
+fn test_splice_out_of_bounds() {
+    let mut cev = Cev::from([1, 2, 3, 4, 5]);
+    let a = [10, 11, 12];
+    cev.splice(5..6, a);
+}
+
+#[test]
+fn test_splice_items_zero_sized() {
+    let mut cev = Cev::from([(), (), ()]);
+    let cev2 = Cev::from([]);
+    let t: Vec<_> = cev.splice(1..2, cev2.iter().cloned()).collect();
+    assert_eq!(cev, [(), ()]);
+    assert_eq!(t, [()]);
+
+    let t: Vec<_> = cev.splice(1..1, [(), (), ()]).collect();
+    assert_eq!(cev.len(), 5);
+    assert_eq!(t, []);
+}
+
+#[test]
+fn test_splice_unbounded() {
+    let mut cev = Cev::from([1, 2, 3, 4, 5]);
+    let t: Vec<_> = cev.splice(.., None).collect();
+    assert_eq!(cev, []);
+    assert_eq!(t, [1, 2, 3, 4, 5]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_splice_forget() {
+    let mut cev = Cev::from([1, 2, 3, 4, 5]);
+    let a = [10, 11, 12];
+    core::mem::forget(cev.splice(2..4, a));
+    assert_eq!(cev, [5]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_splice_grow() {
+    let mut cev: Cev<String> = ["a", "b", "c"].iter().map(|x| x.to_string()).collect();
+    let capacity = cev.capacity();
+    cev.splice(1..2, (0..20).map(|x| x.to_string()));
+    assert!(cev.capacity() > capacity);
+    assert_eq!(cev.len(), 22);
+    assert_eq!(cev[0], "a");
+    assert_eq!(cev[1], "0");
+    assert_eq!(cev[20], "19");
+    assert_eq!(cev[21], "c");
+    assert!(test_ptr_pos(&cev));
+
+    // The lower bound of the size hint is too small, the rest is collected.
+    let mut cev = Cev::from([1, 2, 3]);
+    cev.splice(1..1, (0..40).filter(|x| x % 4 == 0));
+    assert_eq!(cev, [1, 0, 4, 8, 12, 16, 20, 24, 28, 32, 36, 2, 3]);
+    assert!(test_ptr_pos(&cev));
+
+    // Headroom in front is used before growing.
+    let mut cev = Cev::with_capacity(10);
+    cev.extend_front_ordered([1, 2, 3]);
+    let raw_ptr = cev.raw_ptr();
+    cev.splice(2..2, [7, 8, 9]);
+    assert_eq!(cev, [1, 2, 7, 8, 9, 3]);
+    assert_eq!(cev.raw_ptr(), raw_ptr);
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev = Cev::new();
+    cev.splice(.., [1, 2, 3]);
+    assert_eq!(cev, [1, 2, 3]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_splice_iterator_panic() {
+    let mut cev: Cev<_> = (0..5).map(|x| x.to_string()).collect();
+    catch_unwind(AssertUnwindSafe(|| {
+        cev.splice(
+            1..3,
+            (10..40).map(|x| if x == 15 { panic!("iterator panic") } else { x.to_string() }),
+        );
+    }))
+    .unwrap_err();
+    assert_eq!(cev, ["0", "10", "11", "12", "13", "14", "3", "4"]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_extract_if_empty() {
+    let mut cev: Cev<i32> = Cev::new();
+    {
+        let mut iter = cev.extract_if(.., |_| true);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+    }
+    assert_eq!(cev.len(), 0);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_extract_if_zst() {
+    let mut cev = Cev::from([(), (), (), (), ()]);
+    let initial_len = cev.len();
+    let mut count = 0;
+    {
+        let mut iter = cev.extract_if(.., |_| true);
+        assert_eq!(iter.size_hint(), (0, Some(initial_len)));
+        while let Some(_) = iter.next() {
+            count += 1;
+            assert_eq!(iter.size_hint(), (0, Some(initial_len - count)));
+        }
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+    assert_eq!(count, initial_len);
+    assert_eq!(cev.len(), 0);
+}
+
+#[test]
+fn test_extract_if_false() {
+    let mut cev = Cev::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    let initial_len = cev.len();
+    let mut count = 0;
+    {
+        let mut iter = cev.extract_if(.., |_| false);
+        assert_eq!(iter.size_hint(), (0, Some(initial_len)));
+        for _ in iter.by_ref() {
+            count += 1;
+        }
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+    assert_eq!(count, 0);
+    assert_eq!(cev.len(), initial_len);
+    assert_eq!(cev, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_extract_if_complex() {
+    let mut cev = Cev::from([1, 2, 4, 6, 7, 9, 11, 13, 15, 17, 18, 20, 22, 24, 26, 27, 29, 31, 33, 34, 35, 36, 37, 39]);
+    let removed = cev.extract_if(.., |x| *x % 2 == 0).collect::<Vec<_>>();
+    assert_eq!(removed.len(), 10);
+    assert_eq!(removed, [2, 4, 6, 18, 20, 22, 24, 26, 34, 36]);
+    assert_eq!(cev.len(), 14);
+    assert_eq!(cev, [1, 7, 9, 11, 13, 15, 17, 27, 29, 31, 33, 35, 37, 39]);
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev: Cev<_> = (0..10).map(|x| x.to_string()).collect();
+    let removed = cev.extract_if(2..8, |x| x.len() == 1 && x.as_bytes()[0] % 2 == 1).collect::<Vec<_>>();
+    assert_eq!(removed, ["3", "5", "7"]);
+    assert_eq!(cev, ["0", "1", "2", "4", "6", "8", "9"]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_extract_if_unconsumed() {
+    let mut cev = Cev::from([1, 2, 3, 4]);
+    let drain = cev.extract_if(.., |&mut x| x % 2 != 0);
+    drop(drain);
+    assert_eq!(cev, [1, 2, 3, 4]);
+
+    let mut cev = Cev::from([1, 2, 3, 4, 5, 6]);
+    let mut drain = cev.extract_if(.., |&mut x| x % 2 == 0);
+    assert_eq!(drain.next(), Some(2));
+    drop(drain);
+    assert_eq!(cev, [1, 3, 4, 5, 6]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_extract_if_predicate_panic() {
+    let mut cev: Cev<_> = (0..8).map(Rc::new).collect();
+    let check = cev.clone();
+    let mut removed = Vec::new();
+
+    catch_unwind(AssertUnwindSafe(|| {
+        for x in cev.extract_if(.., |x| match **x {
+            3 => panic!("predicate panic"),
+            x => x % 2 == 1,
+        }) {
+            removed.push(x);
+        }
+    }))
+    .unwrap_err();
+
+    assert_eq!(removed, [1].map(Rc::new));
+    assert_eq!(cev, [0, 2, 3, 4, 5, 6, 7].map(Rc::new));
+    assert!(test_ptr_pos(&cev));
+    drop(removed);
+    assert_eq!(Rc::strong_count(&check[1]), 1);
+    assert_eq!(Rc::strong_count(&check[2]), 2);
+}
+
+#[test]
+fn test_extract_if_forget() {
+    let mut cev = Cev::from([1, 2, 3, 4, 5]);
+    core::mem::forget(cev.extract_if(1..3, |_| true));
+    assert_eq!(cev, [4, 5]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_extract_if_fused() {
+    let mut cev = Cev::from([1, 2, 3, 4, 5, 6, 7]);
+    {
+        let mut iter = cev.extract_if(1..6, |x| *x % 2 == 0);
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(4));
+        assert_eq!(iter.next(), Some(6));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+    assert_eq!(cev, [1, 3, 5, 7]);
+    assert!(test_ptr_pos(&cev));
+
+    fn assert_fused<I: core::iter::FusedIterator>(_: &I) {}
+    assert_fused(&cev.extract_if(.., |_| true));
+}
+
+#[test]
+fn test_hash() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashMap;
+    use std::hash::{Hash, Hasher};
+
+    fn hash<T: Hash + ?Sized>(t: &T) -> u64 {
+        let mut s = DefaultHasher::new();
+        t.hash(&mut s);
+        s.finish()
+    }
+
+    let cev = Cev::from([1, 2, 3]);
+    assert_eq!(hash(&cev), hash(&[1, 2, 3][..]));
+    assert_eq!(hash(&cev), hash(&vec![1, 2, 3]));
+
+    let mut map = HashMap::new();
+    map.insert(cev, "one two three");
+    assert_eq!(map.get(&Cev::from([1, 2, 3])), Some(&"one two three"));
+    assert_eq!(map.get(&[1, 2, 3][..]), Some(&"one two three"));
+}
+
+#[test]
+fn test_into_boxed_slice() {
+    let cev = Cev::from([1, 2, 3]);
+    let raw_ptr = cev.raw_ptr();
+    let slice = cev.into_boxed_slice();
+    assert_eq!(&*slice, [1, 2, 3]);
+    assert_eq!(slice.as_ptr(), raw_ptr);
+
+    let mut cev: Cev<String> = Cev::with_capacity(8);
+    cev.push("b".to_string());
+    cev.push("a".to_string());
+    let slice: Box<[String]> = cev.into();
+    assert_eq!(&*slice, ["a", "b"]);
+
+    let cev: Cev<u8> = Cev::with_capacity(8);
+    assert!(cev.into_boxed_slice().is_empty());
+
+    let cev = Cev::from([(), ()]);
+    assert_eq!(cev.into_boxed_slice().len(), 2);
+}
+
+#[test]
+fn test_from_boxed_slice() {
+    let slice: Box<[String]> = vec!["a".to_string(), "b".to_string()].into_boxed_slice();
+    let ptr = slice.as_ptr();
+    let cev = Cev::from(slice);
+    assert_eq!(cev, ["a", "b"]);
+    assert_eq!(cev.raw_ptr(), ptr);
+    assert_eq!(cev.capacity(), 2);
+    assert!(test_ptr_pos(&cev));
+
+    let cev = Cev::<u8>::from(Box::<[u8]>::default());
+    assert_eq!(cev.capacity(), 0);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_vec_deque() {
+    use std::collections::VecDeque;
+
+    let mut deque = VecDeque::with_capacity(4);
+    deque.push_back(3);
+    deque.push_back(4);
+    deque.push_front(2);
+    deque.push_front(1);
+    let cev = Cev::from(deque);
+    assert_eq!(cev, [1, 2, 3, 4]);
+    assert_eq!(cev.capacity(), 4);
+    assert!(test_ptr_pos(&cev));
+
+    let raw_ptr = cev.raw_ptr();
+    let deque = VecDeque::from(cev);
+    assert_eq!(deque, [1, 2, 3, 4]);
+    assert_eq!(deque.as_slices().0.as_ptr(), raw_ptr);
+
+    let mut cev = Cev::with_capacity(10);
+    cev.extend_front_ordered(["a".to_string(), "b".to_string()]);
+    let deque: VecDeque<_> = cev.into();
+    assert_eq!(deque, ["a", "b"]);
+    assert_eq!(deque.capacity(), 10);
+}
+
+#[test]
+fn test_rc_arc() {
+    use std::sync::Arc;
+
+    let rc: Rc<[String]> = Cev::from(["a".to_string(), "b".to_string()]).into();
+    assert_eq!(&*rc, ["a", "b"]);
+
+    let mut cev = Cev::with_capacity(5);
+    cev.push(2);
+    cev.push(1);
+    let arc: Arc<[i32]> = cev.into();
+    assert_eq!(&*arc, [1, 2]);
+}
+
+#[test]
+fn test_cow() {
+    use std::borrow::Cow;
+
+    let cev = Cev::from([1, 2, 3]);
+    let cow: Cow<'_, [i32]> = (&cev).into();
+    assert!(matches!(cow, Cow::Borrowed(_)));
+    assert_eq!(*cow, [1, 2, 3]);
+
+    let cev2 = Cev::from(cow);
+    assert_eq!(cev2, cev);
+    assert!(test_ptr_pos(&cev2));
+
+    let raw_ptr = cev.raw_ptr();
+    let cow: Cow<'_, [i32]> = cev.into();
+    assert!(matches!(cow, Cow::Owned(_)));
+    assert_eq!(cow.as_ptr(), raw_ptr);
+
+    let cev = Cev::from(cow);
+    assert_eq!(cev, [1, 2, 3]);
+    assert_eq!(cev.raw_ptr(), raw_ptr);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_growth_policy_push() {
+    let mut cev = Cev::new().with_growth_policy(FixedIncrement(3));
+    let mut caps = Vec::new();
+    for i in 0..10u64 {
+        cev.push(i);
+        caps.push(cev.capacity());
+        assert!(test_ptr_pos(&cev));
+    }
+    assert_eq!(caps, [4, 4, 4, 4, 7, 7, 7, 10, 10, 10]);
+    assert_eq!(cev, [9, 8, 7, 6, 5, 4, 3, 2, 1, 0]);
+
+    let mut cev = Cev::with_capacity(4).with_growth_policy(OneAndHalf);
+    cev.extend_front_ordered(0..5);
+    assert_eq!(cev.capacity(), 6);
+    cev.push(5);
+    cev.push(6);
+    assert_eq!(cev.capacity(), 9);
+    assert_eq!(cev, [6, 5, 0, 1, 2, 3, 4]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_growth_policy_insert_append_reserve() {
+    let mut cev = Cev::from([1, 2]).with_growth_policy(FixedIncrement(10));
+    cev.insert(1, 3);
+    assert_eq!(cev.capacity(), 12);
+    assert_eq!(cev, [1, 3, 2]);
+    assert!(test_ptr_pos(&cev));
+
+    let mut other = Cev::from([0; 10]).with_growth_policy(FixedIncrement(0));
+    cev.append(&mut other);
+    assert_eq!(cev.capacity(), 22);
+    assert_eq!(cev.len(), 13);
+    assert!(test_ptr_pos(&cev));
+
+    cev.reserve(10);
+    assert_eq!(cev.capacity(), 32);
+    cev.try_reserve(20).unwrap();
+    assert_eq!(cev.capacity(), 42);
+    assert_eq!(cev[10..], [1, 3, 2]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_reserve_exact() {
+    let mut cev = Cev::new();
+    cev.reserve_exact(0);
+    assert_eq!(cev.capacity(), 0);
+    cev.reserve_exact(1);
+    assert_eq!(cev.capacity(), 1);
+    cev.push(1u8);
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev = Cev::from([1, 2, 3]).with_growth_policy(FixedIncrement(100));
+    cev.reserve_exact(2);
+    assert_eq!(cev.capacity(), 5);
+    cev.reserve_exact(1);
+    assert_eq!(cev.capacity(), 5);
+    assert_eq!(cev, [1, 2, 3]);
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev: Cev<()> = Cev::new();
+    cev.reserve_exact(usize::MAX);
+    assert_eq!(cev.capacity(), usize::MAX);
+
+    let result = catch_unwind(|| Cev::<u16>::new().reserve_exact(usize::MAX));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_growth_policy_page_rounded() {
+    let mut cev: Cev<[u8; 3], _, _> = Cev::new().with_growth_policy(PageRounded(64));
+    cev.push([0; 3]);
+    assert_eq!(cev.capacity(), 21);
+    cev.extend([[1; 3]; 21]);
+    assert_eq!(cev.capacity(), 42);
+    assert_eq!(cev.len(), 22);
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev: Cev<(), _, _> = Cev::new().with_growth_policy(PageRounded(64));
+    cev.push(());
+    assert_eq!(cev.capacity(), usize::MAX);
+}
+
+#[test]
+fn test_growth_policy_closure() {
+    let calls = std::cell::Cell::new(0);
+    let policy = |cap: usize, required: usize, elem_size: usize| {
+        calls.set(calls.get() + 1);
+        assert_eq!(elem_size, 2);
+        cap + required * 2
+    };
+    let mut cev = Cev::new().with_growth_policy(policy);
+    cev.push(1u16);
+    assert_eq!(cev.capacity(), 4);
+    cev.extend([2, 3, 4, 5]);
+    assert_eq!(cev.capacity(), 14);
+    assert_eq!(calls.get(), 2);
+    assert_eq!(cev, [5, 4, 3, 2, 1]);
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev = Cev::new().with_growth_policy(|_: usize, required: usize, _: usize| required);
+    for i in 0..10 {
+        cev.push(i);
+    }
+    assert_eq!(cev.capacity(), 10);
+}
+
+#[test]
+fn test_growth_policy_kept() {
+    let mut cev = Cev::from([1, 2, 3, 4]).with_growth_policy(FixedIncrement(7));
+    let clone = cev.clone();
+    assert_eq!(clone.growth_policy(), &FixedIncrement(7));
+
+    let mut back = cev.split_off(2);
+    let mut front = cev.split_off_front(1);
+    assert_eq!(back.growth_policy(), &FixedIncrement(7));
+    assert_eq!(front.growth_policy(), &FixedIncrement(7));
+    back.push(0);
+    front.push(0);
+    assert_eq!(back.capacity(), 9);
+    assert_eq!(front.capacity(), 8);
+    assert_eq!(back, [0, 3, 4]);
+    assert_eq!(front, [0, 1]);
+    assert_eq!(cev, [2]);
+
+    cev.splice(..0, [5, 6, 7, 8]);
+    assert_eq!(cev.capacity(), 11);
+    assert_eq!(cev, [5, 6, 7, 8, 2]);
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev = cev.with_growth_policy(cev::Doubling);
+    cev.extend_front_ordered(0..7);
+    assert_eq!(cev.capacity(), 22);
+    let vec = cev.into_vec();
+    assert_eq!(vec, [0, 1, 2, 3, 4, 5, 6, 5, 6, 7, 8, 2]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_try_reserve_error_std_error() {
+    let err = Cev::<u16>::new().try_reserve(usize::MAX).unwrap_err();
+    let err: Box<dyn std::error::Error> = Box::new(err);
+    assert_eq!(
+        err.to_string(),
+        "memory allocation failed because the computed capacity exceeded the collection's maximum"
+    );
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn test_into_iter_debug() {
+    let cev = Cev::from(['a', 'b', 'c']);
+    let into_iter = cev.into_iter();
+    let debug = format!("{into_iter:?}");
+    assert_eq!(debug, "IntoIter(['a', 'b', 'c'])");
+}
+
+#[test]
+fn test_cev_clear_drop() {
+    static mut DROPS: u32 = 0;
+    #[allow(dead_code)]
+    struct Elem(i32);
+    impl Drop for Elem {
+        fn drop(&mut self) {
+            unsafe {
+                DROPS += 1;
+            }
+        }
+    }
+
+    let mut cev = Cev::with_capacity(5);
+    let mut cev_three = Cev::from([Elem(1), Elem(2), Elem(3)]);
+    let mut cev_five = Cev::from([Elem(1), Elem(2), Elem(3), Elem(4), Elem(5)]);
+    assert_eq!(unsafe { DROPS }, 0);
+    cev.append(&mut cev_three);
+    cev.clear();
+    assert_eq!(unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) }, cev.capacity() as isize);
+    assert_eq!(unsafe { DROPS }, 3);
+    unsafe { DROPS = 0 };
+    cev.append(&mut cev_five);
+    cev.clear();
+    assert_eq!(unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) }, cev.capacity() as isize);
+    assert_eq!(unsafe { DROPS }, 5);
+}
+
+#[test]
+fn test_clear() {
+    let mut cev: Cev<u8> = Cev::new();
+    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+    cev.clear();
+    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+    assert_eq!(cev, Cev::new());
+
+    let mut cev: Cev<u8> = Cev::with_capacity(0);
+    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+    cev.clear();
+    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+    assert_eq!(cev, Cev::new());
+
+    let mut cev: Cev<u8> = Cev::from([1]);
+    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+    cev.clear();
+    assert_eq!(cev, []);
+    assert_eq!(
+        unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
+        cev.capacity() as isize
+    );
+    cev.reserve(8);
+    assert_eq!(
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - 1) as isize
+        cev.capacity() as isize
+    );
+    assert_eq!(cev, []);
+    cev.clear();
+    assert_eq!(
+        unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
+        cev.capacity() as isize
+    );
+    assert_eq!(cev, []);
+    cev.push(4);
+    cev.push(3);
+    cev.push(2);
+    cev.push(1);
+    cev.clear();
+    assert_eq!(
+        unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
+        cev.capacity() as isize
+    );
+    assert_eq!(cev, []);
+
+    let mut cev: Cev<()> = Cev::with_capacity(0);
+    cev.clear();
+    assert_eq!(cev, []);
+    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+
+    let mut cev: Cev<()> = Cev::with_capacity(1);
+    cev.clear();
+    assert_eq!(cev, []);
+    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+    unsafe {
+        cev.set_len_ptr(0);
+    };
+    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+
+    let mut cev: Cev<()> = Cev::with_capacity(10);
+    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+    cev.clear();
+    assert_eq!(cev, []);
+    unsafe {
+        cev.set_len_ptr(0);
+    };
+    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+}
+
+#[test]
+fn test_set_len_ptr() {
+    let mut cev: Cev<String> = Cev::new();
+    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+    unsafe {
+        cev.set_len_ptr(0);
+    };
+    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+
+    let mut cev: Cev<u8> = Cev::from([1]);
+    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+    unsafe {
+        cev.set_len_ptr(0);
+    };
+    assert_eq!(
+        unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
+        cev.capacity() as isize
+    );
+    cev.reserve(8);
+    cev.push(4);
+    cev.push(3);
+    cev.push(2);
+    cev.push(1);
+    unsafe {
+        cev.set_len_ptr(0);
+    };
+    assert_eq!(
+        unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
+        cev.capacity() as isize
     );
     cev.push(8);
     cev.push(7);
@@ -1023,7 +2485,7 @@ fn test_set_len_ptr() {
     };
     assert_eq!(
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - 1) as isize
+        cev.capacity() as isize
     );
 
     let mut cev: Cev<()> = Cev::with_capacity(0);
@@ -1055,36 +2517,42 @@ fn test_set_len_ptr() {
     assert_eq!(cev.as_ptr(), cev.raw_ptr());
 
     let mut cev: Cev<Cev<u8>> = Cev::with_capacity(1);
-    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+    assert_eq!(
+        unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
+        cev.capacity() as isize
+    );
     unsafe {
         cev.set_len_ptr(0);
     };
-    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+    assert_eq!(
+        unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
+        cev.capacity() as isize
+    );
 
     let mut cev: Cev<Cev<u8>> = Cev::with_capacity(10);
     assert_eq!(
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - 1) as isize
+        cev.capacity() as isize
     );
     unsafe {
         cev.set_len_ptr(0);
     };
     assert_eq!(
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - 1) as isize
+        cev.capacity() as isize
     );
 
     let mut cev: Cev<u8> = Cev::with_capacity(4);
     assert_eq!(
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - 1) as isize
+        cev.capacity() as isize
     );
     unsafe {
         cev.set_len_ptr(0);
     };
     assert_eq!(
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - 1) as isize
+        cev.capacity() as isize
     );
     cev.push(4);
     cev.push(3);
@@ -1096,13 +2564,13 @@ fn test_set_len_ptr() {
     };
     assert_eq!(
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - 1) as isize
+        cev.capacity() as isize
     );
 
     let mut cev: Cev<u8> = Cev::with_capacity(4);
     assert_eq!(
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - 1) as isize
+        cev.capacity() as isize
     );
     cev.push(4);
     cev.push(3);
@@ -1114,7 +2582,7 @@ fn test_set_len_ptr() {
     };
     assert_eq!(
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - 1) as isize
+        cev.capacity() as isize
     );
 }
 
@@ -0,0 +1,109 @@
+use cev::{Cev, CevBuf};
+
+#[test]
+fn test_put_get_numbers() {
+    let mut buf = CevBuf::with_headroom(64);
+    buf.put_u64_le_front(0x0102_0304_0506_0708);
+    buf.put_u64_be_front(0x0102_0304_0506_0708);
+    buf.put_u32_le_front(0xdead_beef);
+    buf.put_u32_be_front(0xdead_beef);
+    buf.put_u16_le_front(0xabcd);
+    buf.put_u16_be_front(0xabcd);
+    buf.put_u8_front(0xff);
+    assert_eq!(buf.len(), 1 + 2 * 2 + 4 * 2 + 8 * 2);
+    assert_eq!(buf.headroom(), 64 - buf.len());
+    assert_eq!(buf.capacity(), 64);
+    assert_eq!(buf[..5], [0xff, 0xab, 0xcd, 0xcd, 0xab]);
+
+    assert_eq!(buf.get_u8(), Some(0xff));
+    assert_eq!(buf.get_u16_be(), Some(0xabcd));
+    assert_eq!(buf.get_u16_le(), Some(0xabcd));
+    assert_eq!(buf.get_u32_be(), Some(0xdead_beef));
+    assert_eq!(buf.get_u32_le(), Some(0xdead_beef));
+    assert_eq!(buf.get_u64_be(), Some(0x0102_0304_0506_0708));
+    assert_eq!(buf.get_u64_le(), Some(0x0102_0304_0506_0708));
+    assert!(buf.is_empty());
+    assert_eq!(buf.headroom(), 64);
+    assert_eq!(buf.get_u8(), None);
+}
+
+#[test]
+fn test_get_short_buffer_consumes_nothing() {
+    let mut buf = CevBuf::from(&[1u8, 2, 3][..]);
+    assert_eq!(buf.get_u32_be(), None);
+    assert_eq!(buf.get_u64_le(), None);
+    assert_eq!(buf, [1, 2, 3]);
+    assert_eq!(buf.get_u16_le(), Some(0x0201));
+    assert_eq!(buf, [3]);
+}
+
+#[test]
+fn test_layers_do_not_move_payload() {
+    let mut buf = CevBuf::with_headroom(128);
+    buf.put_slice_front(&[0xaa; 64]);
+    let payload = buf.as_ptr();
+
+    for layer in 0..4u8 {
+        buf.put_slice_front(&[layer; 8]);
+        buf.put_u16_be_front(8);
+    }
+    assert_eq!(buf.len(), 64 + 4 * 10);
+    assert_eq!(unsafe { buf.as_ptr().add(40) }, payload);
+
+    for layer in (0..4u8).rev() {
+        assert_eq!(buf.get_u16_be(), Some(8));
+        let mut header = [0; 8];
+        buf.copy_to_slice(&mut header);
+        assert_eq!(header, [layer; 8]);
+    }
+    assert_eq!(buf.as_ptr(), payload);
+    assert_eq!(buf, [0xaa; 64]);
+}
+
+#[test]
+fn test_reserve_headroom() {
+    let mut buf = CevBuf::new();
+    assert_eq!(buf.headroom(), 0);
+    buf.put_slice_front(b"data");
+    buf.reserve_headroom(100);
+    assert!(buf.headroom() >= 100);
+    assert!(buf.try_reserve_headroom(usize::MAX).is_err());
+    buf.put_slice_front(b"");
+    assert_eq!(buf, b"data");
+}
+
+#[test]
+#[should_panic(expected = "cannot advance past the end of the buffer (cnt is 5, len is 4)")]
+fn test_advance_past_end() {
+    let mut buf = CevBuf::from(&b"data"[..]);
+    buf.advance(5);
+}
+
+#[test]
+#[should_panic(expected = "buffer too short (need 2, have 1)")]
+fn test_copy_to_slice_short() {
+    let mut buf = CevBuf::from(&b"d"[..]);
+    buf.copy_to_slice(&mut [0; 2]);
+}
+
+#[test]
+fn test_conversions() {
+    let mut vec = Vec::with_capacity(16);
+    vec.extend_from_slice(b"payload");
+    let mut buf = CevBuf::from(vec);
+    assert_eq!(buf.headroom(), 9);
+    buf.put_slice_front(b"hdr:");
+
+    let cev = Cev::from(buf);
+    assert_eq!(cev, *b"hdr:payload");
+    let buf = CevBuf::from(cev);
+    assert_eq!(buf.headroom(), 5);
+
+    let vec = Vec::from(buf);
+    assert_eq!(vec, b"hdr:payload");
+    assert_eq!(vec.capacity(), 16);
+    assert_eq!(
+        format!("{:?}", CevBuf::from(&b"ab"[..])),
+        "CevBuf { data: [97, 98], headroom: 0 }"
+    );
+}
@@ -0,0 +1,115 @@
+use cev::{CapacityError, Cev, CevSlice};
+use std::mem::MaybeUninit;
+use std::rc::Rc;
+
+#[test]
+fn test_push_pop() {
+    let mut storage = [MaybeUninit::uninit(); 4];
+    let mut cev = CevSlice::new(&mut storage);
+    assert_eq!(cev.pop(), None);
+    for i in 0..4 {
+        assert!(cev.push(i).is_ok());
+    }
+    assert!(cev.is_full());
+    assert_eq!(cev, [3, 2, 1, 0]);
+    assert_eq!(cev.push(4), Err(CapacityError::new(4)));
+
+    for i in (0..4).rev() {
+        assert_eq!(cev.pop(), Some(i));
+    }
+    assert!(cev.is_empty());
+    assert_eq!(cev.remaining_capacity(), 4);
+}
+
+#[test]
+fn test_try_insert_remove() {
+    let mut storage = [MaybeUninit::uninit(); 4];
+    let mut cev = Cev::from_uninit_slice(&mut storage);
+    cev.try_insert(0, 'c').unwrap();
+    cev.try_insert(0, 'a').unwrap();
+    cev.try_insert(1, 'b').unwrap();
+    cev.try_insert(3, 'd').unwrap();
+    assert_eq!(cev, ['a', 'b', 'c', 'd']);
+    assert_eq!(cev.try_insert(2, 'x').unwrap_err().element(), 'x');
+
+    assert_eq!(cev.remove(2), 'c');
+    assert_eq!(cev.remove(0), 'a');
+    assert_eq!(cev, ['b', 'd']);
+}
+
+#[test]
+#[should_panic(expected = "removal index (is 0) should be < len (is 0)")]
+fn test_remove_out_of_bounds() {
+    let mut storage = [MaybeUninit::<u8>::uninit(); 4];
+    let mut cev = CevSlice::new(&mut storage);
+    cev.remove(0);
+}
+
+#[test]
+fn test_truncate_drop() {
+    let rc = Rc::new(());
+    let mut storage = [const { MaybeUninit::uninit() }; 8];
+    let mut cev = CevSlice::new(&mut storage);
+    for _ in 0..6 {
+        cev.push(Rc::clone(&rc)).unwrap();
+    }
+    cev.truncate(2);
+    assert_eq!(cev.len(), 2);
+    assert_eq!(Rc::strong_count(&rc), 3);
+    drop(cev);
+    assert_eq!(Rc::strong_count(&rc), 1);
+
+    let mut cev = CevSlice::new(&mut storage);
+    cev.push(Rc::clone(&rc)).unwrap();
+    cev.clear();
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_into_slice_reuses_storage() {
+    let mut storage = [MaybeUninit::uninit(); 4];
+    let ptr = storage.as_ptr() as *const u32;
+    let mut cev = CevSlice::new(&mut storage);
+    cev.push(2).unwrap();
+    cev.push(1).unwrap();
+    assert_eq!(cev.as_ptr(), ptr.wrapping_add(2));
+
+    let slice = cev.into_slice();
+    slice[0] = 0;
+    assert_eq!(slice, [0, 2]);
+    assert_eq!(unsafe { storage[2].assume_init() }, 0);
+}
+
+#[test]
+fn test_empty_and_zst() {
+    let mut storage: [MaybeUninit<u8>; 0] = [];
+    let mut cev = CevSlice::new(&mut storage);
+    assert_eq!(cev.push(1), Err(CapacityError::new(1)));
+    assert!(cev.into_slice().is_empty());
+
+    let mut storage = [MaybeUninit::uninit(); 3];
+    let mut cev = CevSlice::new(&mut storage);
+    for _ in 0..3 {
+        cev.push(()).unwrap();
+    }
+    assert!(cev.push(()).is_err());
+    assert_eq!(cev.len(), 3);
+}
+
+#[test]
+fn test_traits() {
+    let mut storage_a = [MaybeUninit::uninit(); 2];
+    let mut storage_b = [MaybeUninit::uninit(); 4];
+    let mut a = CevSlice::new(&mut storage_a);
+    let mut b = CevSlice::new(&mut storage_b);
+    a.push(2).unwrap();
+    b.push(3).unwrap();
+    assert!(a < b);
+    assert_eq!(a.cmp(&b), std::cmp::Ordering::Less);
+
+    b.clear();
+    b.push(2).unwrap();
+    assert_eq!(a, b);
+    assert_eq!(format!("{a:?}"), "[2]");
+    assert_eq!((&mut a).into_iter().map(|x| *x).sum::<i32>(), 2);
+}
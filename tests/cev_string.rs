@@ -0,0 +1,146 @@
+use cev::{write_front, Cev, CevString};
+use std::fmt::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+
+#[test]
+fn test_push_front_pop_front() {
+    let mut s = CevString::new();
+    for ch in "añ€😀".chars() {
+        s.push_front(ch);
+    }
+    assert_eq!(s, "😀€ña");
+    assert_eq!(s.len(), 10);
+
+    assert_eq!(s.pop_front(), Some('😀'));
+    assert_eq!(s.pop_front(), Some('€'));
+    s.push_str_front("ß");
+    assert_eq!(s, "ßña");
+    assert_eq!(s.pop_front(), Some('ß'));
+    assert_eq!(s.pop_front(), Some('ñ'));
+    assert_eq!(s.pop_front(), Some('a'));
+    assert_eq!(s.pop_front(), None);
+    assert!(s.is_empty());
+}
+
+#[test]
+fn test_truncate_front() {
+    let mut s = CevString::from("ñu gnu");
+    s.truncate_front(10);
+    assert_eq!(s, "ñu gnu");
+    s.truncate_front(3);
+    assert_eq!(s, "gnu");
+    s.truncate_front(0);
+    assert_eq!(s, "");
+}
+
+#[test]
+#[should_panic]
+fn test_truncate_front_not_char_boundary() {
+    let mut s = CevString::from("ñu");
+    s.truncate_front(2);
+}
+
+#[test]
+fn test_write_keeps_fragment_order() {
+    let mut s = CevString::from("!");
+    let greeting = String::from("Hello");
+    write!(s, "{greeting}, {}", 'w'.to_ascii_lowercase()).unwrap();
+    assert_eq!(s, "Hello, w!");
+
+    s.clear();
+    for i in 0..5 {
+        write_front!(s, "[{i}:{:>3}]", i * 10).unwrap();
+    }
+    assert_eq!(s, "[4: 40][3: 30][2: 20][1: 10][0:  0]");
+
+    s.write_str("a").unwrap();
+    s.write_char('b').unwrap();
+    assert!(s.starts_with("ba["));
+
+    let mut s = CevString::new();
+    write_front!(s, "literal").unwrap();
+    assert_eq!(s, "literal");
+}
+
+struct Failing;
+
+impl fmt::Display for Failing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("partial ")?;
+        Err(fmt::Error)
+    }
+}
+
+struct Panicking;
+
+impl fmt::Display for Panicking {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ñandú ")?;
+        panic!("formatting panicked");
+    }
+}
+
+#[test]
+fn test_write_error_and_panic_keep_utf8() {
+    let mut s = CevString::from("end");
+    assert!(write_front!(s, "é {}{}", Failing, "never").is_err());
+    assert_eq!(s, "é partial end");
+
+    let mut s = CevString::from("end");
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let _ = write_front!(s, "ü {}", Panicking);
+    }));
+    assert!(result.is_err());
+    assert_eq!(s, "ü ñandú end");
+    assert!(std::str::from_utf8(s.as_bytes()).is_ok());
+}
+
+#[test]
+fn test_string_conversions() {
+    let mut string = String::with_capacity(32);
+    string.push_str("name");
+    let ptr = string.as_ptr();
+    let mut s = CevString::from(string);
+    assert_eq!(s.capacity(), 32);
+    s.push_str_front("qualified::");
+    assert_eq!(s, "qualified::name");
+
+    let string = String::from(s);
+    assert_eq!(string, "qualified::name");
+    assert_eq!(string.as_ptr(), ptr);
+    assert_eq!(string.capacity(), 32);
+
+    let bytes: Cev<u8> = CevString::from("abc").into();
+    assert_eq!(bytes, *b"abc");
+}
+
+#[test]
+fn test_from_utf8() {
+    let s = CevString::from_utf8(Cev::from(*b"ok")).unwrap();
+    assert_eq!(s, "ok");
+
+    let err = CevString::from_utf8(Cev::from([b'a', 0xff, b'b'])).unwrap_err();
+    assert_eq!(err.utf8_error().valid_up_to(), 1);
+    assert_eq!(err.as_bytes(), [b'a', 0xff, b'b']);
+    assert_eq!(
+        err.to_string(),
+        "invalid utf-8 sequence of 1 bytes from index 1"
+    );
+    assert_eq!(err.into_bytes(), [b'a', 0xff, b'b']);
+}
+
+#[test]
+fn test_traits() {
+    let s = CevString::from("b");
+    let t = CevString::from("a");
+    assert!(t < s);
+    assert_eq!(s.clone(), s);
+    assert_eq!(format!("{s}|{s:?}"), "b|\"b\"");
+    assert_eq!("b", s);
+    assert_eq!(s, String::from("b"));
+    assert_eq!(CevString::default(), "");
+
+    let mut set = std::collections::HashSet::new();
+    set.insert(s);
+    assert!(set.contains("b"));
+}
@@ -0,0 +1,185 @@
+use cev::{Cev, DeCev, TryReserveErrorKind};
+use std::collections::HashSet;
+use std::rc::Rc;
+
+fn test_headroom<T>(decev: &DeCev<T>) -> bool {
+    decev.front_headroom() + decev.len() + decev.back_headroom() == decev.capacity()
+}
+
+#[test]
+fn test_push_pop_both_ends() {
+    let mut decev = DeCev::new();
+    assert_eq!(decev.capacity(), 0);
+    assert_eq!(decev.pop_front(), None);
+    assert_eq!(decev.pop_back(), None);
+
+    for i in 0..100 {
+        decev.push_front(-i);
+        decev.push_back(i);
+        assert!(test_headroom(&decev));
+    }
+    assert_eq!(decev.len(), 200);
+    assert_eq!(decev[0], -99);
+    assert_eq!(decev[199], 99);
+    assert!(decev.windows(2).all(|w| w[0] <= w[1]));
+
+    for i in (0..100).rev() {
+        assert_eq!(decev.pop_front(), Some(-i));
+        assert_eq!(decev.pop_back(), Some(i));
+    }
+    assert!(decev.is_empty());
+    assert!(test_headroom(&decev));
+}
+
+#[test]
+fn test_one_end_rebalances() {
+    let mut decev = DeCev::new();
+    for i in 0..1000 {
+        decev.push_front(i);
+    }
+    assert!(decev.back_headroom() > 0);
+    assert!(decev.capacity() < 4000);
+
+    let cap = decev.capacity();
+    for _ in 0..900 {
+        decev.pop_front();
+    }
+    for i in 0..1000 {
+        decev.push_back(i);
+    }
+    assert_eq!(decev.capacity(), cap);
+    assert_eq!(decev.len(), 1100);
+    assert!(test_headroom(&decev));
+}
+
+#[test]
+fn test_with_headroom() {
+    let mut decev = DeCev::with_headroom(3, 2);
+    assert_eq!(decev.capacity(), 5);
+    assert_eq!(decev.front_headroom(), 3);
+    assert_eq!(decev.back_headroom(), 2);
+
+    decev.push_front(2);
+    decev.push_front(1);
+    decev.push_front(0);
+    decev.push_back(3);
+    decev.push_back(4);
+    assert_eq!(decev, [0, 1, 2, 3, 4]);
+    assert_eq!(decev.capacity(), 5);
+
+    decev.push_back(5);
+    assert_eq!(decev, [0, 1, 2, 3, 4, 5]);
+    assert_eq!(decev.capacity(), 10);
+    assert!(decev.front_headroom() > 0);
+    assert!(test_headroom(&decev));
+}
+
+#[test]
+fn test_reserve() {
+    let mut decev: DeCev<u64> = DeCev::with_capacity(4);
+    decev.push_back(1);
+    decev.reserve_front(10);
+    assert!(decev.front_headroom() >= 10);
+    decev.reserve_back(20);
+    assert!(decev.back_headroom() >= 20);
+    assert_eq!(decev, [1]);
+
+    let err = decev.try_reserve_front(usize::MAX).unwrap_err();
+    assert!(matches!(err.kind(), TryReserveErrorKind::CapacityOverflow));
+    let err = decev.try_reserve_back(usize::MAX / 2).unwrap_err();
+    assert!(matches!(err.kind(), TryReserveErrorKind::CapacityOverflow));
+    assert_eq!(decev, [1]);
+}
+
+#[test]
+fn test_slices() {
+    let mut decev = DeCev::new();
+    decev.extend_from_slice(&[String::from("c"), String::from("d")]);
+    decev.prepend_slice(&[String::from("a"), String::from("b")]);
+    decev.extend(["e".to_string()]);
+    assert_eq!(decev, ["a", "b", "c", "d", "e"]);
+
+    decev.as_mut_slice().reverse();
+    assert_eq!(decev.as_slice(), ["e", "d", "c", "b", "a"]);
+
+    decev.clear();
+    assert!(decev.is_empty());
+    assert!(test_headroom(&decev));
+}
+
+#[test]
+fn test_clone_eq_hash() {
+    let decev: DeCev<_> = (0..10).collect();
+    let clone = decev.clone();
+    assert_eq!(clone, decev);
+    assert_eq!(clone.front_headroom(), 0);
+    assert!(decev < DeCev::from(vec![1]));
+
+    let mut set = HashSet::new();
+    set.insert(decev);
+    assert!(set.contains(&clone));
+    assert_eq!(format!("{:?}", DeCev::from(vec![1, 2])), "[1, 2]");
+}
+
+#[test]
+fn test_drop() {
+    let rc = Rc::new(());
+    let mut decev = DeCev::new();
+    for _ in 0..10 {
+        decev.push_front(Rc::clone(&rc));
+        decev.push_back(Rc::clone(&rc));
+    }
+    drop(decev.pop_front());
+    drop(decev.pop_back());
+    assert_eq!(Rc::strong_count(&rc), 19);
+    drop(decev);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_zst() {
+    let mut decev = DeCev::new();
+    for _ in 0..10 {
+        decev.push_front(());
+        decev.push_back(());
+    }
+    assert_eq!(decev.len(), 20);
+    assert_eq!(decev.capacity(), usize::MAX);
+    assert_eq!(decev.pop_front(), Some(()));
+    assert_eq!(decev.pop_back(), Some(()));
+    assert_eq!(decev.len(), 18);
+
+    let cev = Cev::from(decev);
+    assert_eq!(cev.len(), 18);
+    let decev = DeCev::from(cev);
+    assert_eq!(Vec::from(decev).len(), 18);
+}
+
+#[test]
+fn test_conversions() {
+    let mut cev = Cev::with_capacity(8);
+    cev.extend_front_ordered([1, 2, 3]);
+    let mut decev = DeCev::from(cev);
+    assert_eq!(decev.front_headroom(), 5);
+    decev.push_back(4);
+    decev.push_front(0);
+    assert_eq!(decev, [0, 1, 2, 3, 4]);
+
+    let mut cev = Cev::from(decev);
+    assert_eq!(cev, [0, 1, 2, 3, 4]);
+    cev.push(-1);
+    assert_eq!(cev, [-1, 0, 1, 2, 3, 4]);
+
+    let mut vec = Vec::from(DeCev::from(cev));
+    vec.push(5);
+    assert_eq!(vec, [-1, 0, 1, 2, 3, 4, 5]);
+
+    let decev = DeCev::from(vec);
+    assert_eq!(decev.front_headroom(), 0);
+    assert_eq!(decev, [-1, 0, 1, 2, 3, 4, 5]);
+
+    let empty = Cev::from(DeCev::<u8>::with_capacity(4));
+    assert_eq!(empty.capacity(), 4);
+    assert_eq!(Cev::from(DeCev::<u8>::new()).capacity(), 0);
+    assert_eq!(Vec::from(DeCev::<u8>::new()).capacity(), 0);
+}
@@ -0,0 +1,192 @@
+use cev::{Cev, SmallCev};
+use std::rc::Rc;
+
+#[test]
+fn test_push_pop_inline_and_spilled() {
+    let mut small: SmallCev<i32, 4> = SmallCev::new();
+    assert_eq!(small.pop(), None);
+    for i in 0..4 {
+        small.push(i);
+    }
+    assert!(!small.spilled());
+    assert_eq!(small, [3, 2, 1, 0]);
+    assert_eq!(small.pop(), Some(3));
+
+    for i in 3..20 {
+        small.push(i);
+    }
+    assert!(small.spilled());
+    assert_eq!(small.len(), 20);
+    assert_eq!(small[0], 19);
+    assert_eq!(small[19], 0);
+
+    for i in (0..20).rev() {
+        assert_eq!(small.pop(), Some(i));
+    }
+    assert_eq!(small.pop(), None);
+    assert!(small.spilled());
+}
+
+#[test]
+fn test_insert_remove() {
+    let mut small: SmallCev<_, 4> = SmallCev::new();
+    small.insert(0, 'c');
+    small.insert(0, 'a');
+    small.insert(1, 'b');
+    small.insert(3, 'd');
+    assert_eq!(small, ['a', 'b', 'c', 'd']);
+    assert!(!small.spilled());
+
+    assert_eq!(small.remove(2), 'c');
+    assert_eq!(small.remove(0), 'a');
+    assert_eq!(small, ['b', 'd']);
+
+    small.insert(1, 'c');
+    small.insert(0, 'a');
+    small.insert(2, 'x');
+    assert!(small.spilled());
+    assert_eq!(small, ['a', 'b', 'x', 'c', 'd']);
+    assert_eq!(small.remove(2), 'x');
+    assert_eq!(small, ['a', 'b', 'c', 'd']);
+}
+
+#[test]
+#[should_panic(expected = "insertion index (is 2) should be <= len (is 1)")]
+fn test_insert_out_of_bounds() {
+    let mut small: SmallCev<_, 4> = SmallCev::from([1]);
+    small.insert(2, 0);
+}
+
+#[test]
+#[should_panic(expected = "removal index (is 1) should be < len (is 1)")]
+fn test_remove_out_of_bounds() {
+    let mut small: SmallCev<_, 4> = SmallCev::from([1]);
+    small.remove(1);
+}
+
+#[test]
+fn test_truncate_clear() {
+    let rc = Rc::new(());
+    let mut small: SmallCev<_, 4> = SmallCev::new();
+    small.extend(std::iter::repeat_with(|| Rc::clone(&rc)).take(3));
+    small.truncate(1);
+    assert_eq!(small.len(), 1);
+    assert_eq!(Rc::strong_count(&rc), 2);
+    small.truncate(5);
+    assert_eq!(small.len(), 1);
+
+    small.extend(std::iter::repeat_with(|| Rc::clone(&rc)).take(10));
+    assert!(small.spilled());
+    small.truncate(2);
+    assert_eq!(Rc::strong_count(&rc), 3);
+    small.clear();
+    assert!(small.is_empty());
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_append() {
+    let mut small: SmallCev<_, 4> = SmallCev::from([3, 4]);
+    let mut other = SmallCev::from([1, 2]);
+    small.append(&mut other);
+    assert_eq!(small, [1, 2, 3, 4]);
+    assert!(other.is_empty());
+    assert!(!small.spilled());
+
+    let mut other = SmallCev::from([-1, 0]);
+    small.append(&mut other);
+    assert_eq!(small, [-1, 0, 1, 2, 3, 4]);
+    assert!(small.spilled());
+
+    let mut other = SmallCev::from(Cev::from([-3, -2]));
+    small.append(&mut other);
+    assert_eq!(small, [-3, -2, -1, 0, 1, 2, 3, 4]);
+    assert!(other.is_empty());
+    other.push(5);
+    assert_eq!(other, [5]);
+}
+
+#[test]
+fn test_cev_conversions() {
+    let cev = Cev::from([1, 2, 3]);
+    let ptr = cev.as_ptr();
+    let small: SmallCev<_, 8> = SmallCev::from(cev);
+    assert!(small.spilled());
+    assert_eq!(small.as_ptr(), ptr);
+    let cev = Cev::from(small);
+    assert_eq!(cev.as_ptr(), ptr);
+
+    let small: SmallCev<_, 8> = SmallCev::from([String::from("a"), String::from("b")]);
+    assert!(!small.spilled());
+    let mut cev = small.into_cev();
+    assert_eq!(cev, ["a", "b"]);
+    cev.push(String::from("c"));
+    assert_eq!(cev, ["c", "a", "b"]);
+
+    let empty: SmallCev<u8, 8> = SmallCev::new();
+    let cev = empty.into_cev();
+    assert_eq!(cev.capacity(), 0);
+}
+
+#[test]
+fn test_collect_clone_eq() {
+    let small: SmallCev<_, 4> = (0..3).collect();
+    assert!(!small.spilled());
+    assert_eq!(small, [0, 1, 2]);
+    let clone = small.clone();
+    assert_eq!(clone, small);
+
+    let big: SmallCev<_, 4> = (0..10).collect();
+    assert!(big.spilled());
+    assert_eq!(big, (0..10).collect::<Vec<_>>()[..]);
+    assert_eq!(big.clone(), big);
+    assert!(small < big);
+    assert_eq!(format!("{:?}", small), "[0, 1, 2]");
+}
+
+#[test]
+fn test_collect_unknown_size() {
+    let small: SmallCev<_, 4> = (0..8).filter(|x| x % 2 == 0).collect();
+    assert!(!small.spilled());
+    assert_eq!(small, [0, 2, 4, 6]);
+
+    let small: SmallCev<_, 4> = (0..10).filter(|x| x % 2 == 0).map(|x| x.to_string()).collect();
+    assert!(small.spilled());
+    assert_eq!(small, ["0", "2", "4", "6", "8"]);
+
+    let small: SmallCev<u8, 4> = std::iter::empty().collect();
+    assert!(!small.spilled());
+    assert!(small.is_empty());
+}
+
+#[test]
+fn test_collect_panic() {
+    let rc = Rc::new(());
+    for panic_at in [2, 6] {
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            (0..8)
+                .filter(|_| true)
+                .map(|x| if x == panic_at { panic!("iterator panic") } else { Rc::clone(&rc) })
+                .collect::<SmallCev<_, 4>>()
+        }));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
+
+#[test]
+fn test_zero_inline_and_zst() {
+    let mut small: SmallCev<u8, 0> = SmallCev::new();
+    assert_eq!(small.capacity(), 0);
+    small.push(1);
+    assert!(small.spilled());
+    assert_eq!(small, [1]);
+
+    let mut small: SmallCev<(), 2> = SmallCev::new();
+    for _ in 0..5 {
+        small.push(());
+    }
+    assert_eq!(small.len(), 5);
+    assert_eq!(small.pop(), Some(()));
+    assert_eq!(small.into_cev().len(), 4);
+}
@@ -0,0 +1,352 @@
+use cev::{AllocError, Allocator, Cev, Global, TryReserveErrorKind};
+use std::alloc::Layout;
+use std::cell::Cell;
+use std::ptr::NonNull;
+use std::rc::Rc;
+
+#[derive(Clone, Default)]
+struct Counting {
+    allocs: Rc<Cell<usize>>,
+    grows: Rc<Cell<usize>>,
+    deallocs: Rc<Cell<usize>>,
+}
+
+impl Counting {
+    fn live(&self) -> usize {
+        self.allocs.get() - self.deallocs.get()
+    }
+}
+
+unsafe impl Allocator for Counting {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        self.allocs.set(self.allocs.get() + 1);
+        Global.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.deallocs.set(self.deallocs.get() + 1);
+        Global.deallocate(ptr, layout)
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        self.grows.set(self.grows.get() + 1);
+        Global.grow(ptr, old_layout, new_layout)
+    }
+}
+
+struct Failing;
+
+unsafe impl Allocator for Failing {
+    fn allocate(&self, _layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        Err(AllocError)
+    }
+
+    unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {
+        unreachable!()
+    }
+}
+
+/// Hands out blocks of `BLOCK` bytes and grows them in place up to that size.
+#[derive(Clone, Default)]
+struct InPlace {
+    grows: Rc<Cell<usize>>,
+}
+
+impl InPlace {
+    const BLOCK: usize = 1024;
+
+    fn block(layout: Layout) -> Layout {
+        Layout::from_size_align(Self::BLOCK, layout.align()).unwrap()
+    }
+}
+
+unsafe impl Allocator for InPlace {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        if layout.size() > Self::BLOCK {
+            return Err(AllocError);
+        }
+        let ptr = Global.allocate(Self::block(layout))?;
+        Ok(NonNull::slice_from_raw_parts(ptr.cast(), layout.size()))
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        Global.deallocate(ptr, Self::block(layout))
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        _old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        if new_layout.size() > Self::BLOCK {
+            return Err(AllocError);
+        }
+        self.grows.set(self.grows.get() + 1);
+        Ok(NonNull::slice_from_raw_parts(ptr, new_layout.size()))
+    }
+}
+
+fn test_ptr_pos<T, A: Allocator>(cev: &Cev<T, A>) -> bool {
+    if cev.capacity() == 0 {
+        cev.as_ptr() == NonNull::<T>::dangling().as_ptr()
+    } else {
+        unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) == ((cev.capacity() - cev.len()) as isize) }
+    }
+}
+
+#[test]
+fn test_new_in() {
+    let alloc = Counting::default();
+    let mut cev = Cev::new_in(alloc.clone());
+    assert_eq!(alloc.allocs.get(), 0);
+    assert!(test_ptr_pos(&cev));
+
+    for i in 0..100 {
+        cev.push(i);
+    }
+    assert_eq!(alloc.allocs.get(), 1);
+    assert!(alloc.grows.get() > 1);
+    assert_eq!(alloc.live(), 1);
+    assert_eq!(cev.len(), 100);
+    assert_eq!(cev[0], 99);
+    assert_eq!(cev[99], 0);
+    assert!(test_ptr_pos(&cev));
+
+    cev.shrink_to_fit();
+    assert_eq!(cev.capacity(), 100);
+    assert_eq!(alloc.live(), 1);
+    assert!(test_ptr_pos(&cev));
+
+    drop(cev);
+    assert_eq!(alloc.live(), 0);
+}
+
+#[test]
+fn test_with_capacity_in() {
+    let alloc = Counting::default();
+    let mut cev = Cev::with_capacity_in(4, alloc.clone());
+    assert_eq!(cev.capacity(), 4);
+    assert_eq!(alloc.live(), 1);
+    assert!(test_ptr_pos(&cev));
+
+    cev.push(String::from("a"));
+    cev.push(String::from("b"));
+    assert_eq!(cev, ["b", "a"]);
+    assert!(test_ptr_pos(&cev));
+
+    let cev_zero: Cev<u8, _> = Cev::with_capacity_in(0, alloc.clone());
+    assert_eq!(cev_zero.capacity(), 0);
+    assert_eq!(alloc.live(), 1);
+
+    drop(cev);
+    drop(cev_zero);
+    assert_eq!(alloc.live(), 0);
+}
+
+#[test]
+fn test_allocator() {
+    let alloc = Counting::default();
+    let mut cev = Cev::new_in(alloc.clone());
+    cev.push(1);
+    assert!(Rc::ptr_eq(&cev.allocator().allocs, &alloc.allocs));
+    let _: &Global = Cev::<u8>::new().allocator();
+}
+
+#[test]
+fn test_from_raw_parts_in() {
+    let alloc = Counting::default();
+    let layout = Layout::array::<u32>(4).unwrap();
+    let raw_ptr = alloc.allocate(layout).unwrap().as_ptr().cast::<u32>();
+
+    let cev = unsafe {
+        raw_ptr.add(2).write(1);
+        raw_ptr.add(3).write(2);
+        Cev::from_raw_parts_in(raw_ptr.add(2), raw_ptr, 2, 4, alloc.clone())
+    };
+    assert_eq!(cev, [1, 2]);
+    assert_eq!(cev.capacity(), 4);
+    assert!(test_ptr_pos(&cev));
+
+    drop(cev);
+    assert_eq!(alloc.live(), 0);
+}
+
+#[test]
+fn test_try_alloc_in_error() {
+    let err = Cev::<u64, _>::try_with_capacity_in(4, Failing).unwrap_err();
+    assert!(matches!(err.kind(), TryReserveErrorKind::AllocError { .. }));
+
+    let mut cev = Cev::<u64, _>::new_in(Failing);
+    let err = cev.try_reserve(4).unwrap_err();
+    assert!(matches!(err.kind(), TryReserveErrorKind::AllocError { .. }));
+    let (value, err) = cev.try_push(1).unwrap_err();
+    assert_eq!(value, 1);
+    assert!(matches!(err.kind(), TryReserveErrorKind::AllocError { .. }));
+    assert_eq!(cev, []);
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev = Cev::<(), _>::new_in(Failing);
+    cev.push(());
+    assert_eq!(cev.len(), 1);
+}
+
+#[test]
+fn test_try_push_insert_return_value() {
+    let mut cev = Cev::<String, _>::new_in(Failing);
+
+    let (value, err) = cev.try_push("pushed".to_string()).unwrap_err();
+    assert_eq!(value, "pushed");
+    assert!(matches!(err.kind(), TryReserveErrorKind::AllocError { .. }));
+
+    let (value, err) = cev.try_insert(0, "inserted".to_string()).unwrap_err();
+    assert_eq!(value, "inserted");
+    assert!(matches!(err.kind(), TryReserveErrorKind::AllocError { .. }));
+    assert!(cev.is_empty());
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_into_iter_in() {
+    let alloc = Counting::default();
+    let mut cev = Cev::new_in(alloc.clone());
+    cev.extend_front_ordered((0..10).map(|i| i.to_string()));
+
+    let mut iter = cev.into_iter();
+    assert_eq!(iter.next().as_deref(), Some("0"));
+    assert_eq!(iter.next_back().as_deref(), Some("9"));
+    assert_eq!(alloc.live(), 1);
+    drop(iter);
+    assert_eq!(alloc.live(), 0);
+
+    let cev: Cev<u8, _> = Cev::new_in(alloc.clone());
+    assert_eq!(cev.into_iter().count(), 0);
+    assert_eq!(alloc.live(), 0);
+}
+
+#[test]
+fn test_clone_split_in() {
+    let alloc = Counting::default();
+    let mut cev = Cev::new_in(alloc.clone());
+    cev.extend_front_ordered([1, 2, 3, 4, 5]);
+
+    let clone = cev.clone();
+    assert_eq!(clone, [1, 2, 3, 4, 5]);
+    assert!(test_ptr_pos(&clone));
+    assert_eq!(alloc.live(), 2);
+
+    let back = cev.split_off(3);
+    assert_eq!(back, [4, 5]);
+    let front = cev.split_off_front(1);
+    assert_eq!(front, [1]);
+    assert_eq!(cev, [2, 3]);
+    assert!(test_ptr_pos(&back));
+    assert!(test_ptr_pos(&front));
+    assert!(test_ptr_pos(&cev));
+    assert_eq!(alloc.live(), 4);
+
+    drop((cev, clone, back, front));
+    assert_eq!(alloc.live(), 0);
+}
+
+#[test]
+fn test_drain_splice_in() {
+    let alloc = Counting::default();
+    let mut cev = Cev::new_in(alloc.clone());
+    cev.extend_front_ordered([1, 2, 3, 4, 5]);
+
+    let drained: Vec<_> = cev.drain(1..3).collect();
+    assert_eq!(drained, [2, 3]);
+    assert_eq!(cev, [1, 4, 5]);
+    assert!(test_ptr_pos(&cev));
+
+    let removed: Vec<_> = cev.splice(1..2, [6, 7, 8, 9, 10, 11]).collect();
+    assert_eq!(removed, [4]);
+    assert_eq!(cev, [1, 6, 7, 8, 9, 10, 11, 5]);
+    assert!(test_ptr_pos(&cev));
+
+    let extracted: Vec<_> = cev.extract_if(.., |x| *x % 2 == 0).collect();
+    assert_eq!(extracted, [6, 8, 10]);
+    assert_eq!(cev, [1, 7, 9, 11, 5]);
+    assert!(test_ptr_pos(&cev));
+    assert_eq!(alloc.live(), 1);
+
+    drop(cev);
+    assert_eq!(alloc.live(), 0);
+}
+
+#[test]
+fn test_grow_in_place() {
+    let alloc = InPlace::default();
+    let mut cev = Cev::new_in(alloc.clone());
+    cev.push(0u32);
+    let raw_ptr = cev.raw_ptr();
+
+    for i in 1..256 {
+        cev.push(i);
+        assert!(test_ptr_pos(&cev));
+    }
+    assert_eq!(cev.raw_ptr(), raw_ptr);
+    assert!(alloc.grows.get() > 1);
+    assert_eq!(cev.capacity(), 256);
+    assert!(cev.iter().rev().copied().eq(0..256));
+
+    let (value, err) = cev.try_push(256).unwrap_err();
+    assert_eq!(value, 256);
+    assert!(matches!(err.kind(), TryReserveErrorKind::AllocError { .. }));
+    assert!(cev.iter().rev().copied().eq(0..256));
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_grow_empty_allocated() {
+    let alloc = InPlace::default();
+    let mut cev: Cev<u64, _> = Cev::with_capacity_in(2, alloc.clone());
+    cev.reserve(10);
+    assert_eq!(alloc.grows.get(), 1);
+    assert!(cev.capacity() >= 10);
+    assert!(test_ptr_pos(&cev));
+
+    cev.push(1);
+    cev.insert(1, 2);
+    assert_eq!(cev, [1, 2]);
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_grow_counting() {
+    let alloc = Counting::default();
+    let mut cev = Cev::new_in(alloc.clone());
+    for i in 0..1000 {
+        cev.push(i.to_string());
+    }
+    assert_eq!(alloc.live(), 1);
+    assert!(cev.iter().rev().map(|s| s.parse::<i32>().unwrap()).eq(0..1000));
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_grow_single_call() {
+    let alloc = Counting::default();
+    let mut cev = Cev::with_capacity_in(4, alloc.clone());
+    cev.extend_front_ordered([1, 2, 3, 4]);
+    assert_eq!((alloc.allocs.get(), alloc.grows.get(), alloc.deallocs.get()), (1, 0, 0));
+
+    cev.push(0);
+    assert_eq!((alloc.allocs.get(), alloc.grows.get(), alloc.deallocs.get()), (1, 1, 0));
+    assert_eq!(cev, [0, 1, 2, 3, 4]);
+    assert!(test_ptr_pos(&cev));
+
+    cev.reserve(100);
+    assert_eq!((alloc.allocs.get(), alloc.grows.get(), alloc.deallocs.get()), (1, 2, 0));
+    assert_eq!(cev, [0, 1, 2, 3, 4]);
+    assert!(test_ptr_pos(&cev));
+
+    drop(cev);
+    assert_eq!((alloc.allocs.get(), alloc.grows.get(), alloc.deallocs.get()), (1, 2, 1));
+}
@@ -25,7 +25,7 @@ fn test_extra_append() {
     cev.append(&mut cev_apd);
     assert_eq!(
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - cev.len() - 1) as isize
+        (cev.capacity() - cev.len()) as isize
     );
     assert_eq!(cev_apd.as_ptr(), NonNull::<u8>::dangling().as_ptr());
     assert_eq!(cev, []);
@@ -35,11 +35,11 @@ fn test_extra_append() {
     cev.append(&mut cev_apd);
     assert_eq!(
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - cev.len() - 1) as isize
+        (cev.capacity() - cev.len()) as isize
     );
     assert_eq!(
         unsafe { cev_apd.as_ptr().offset_from(cev_apd.raw_ptr()) },
-        (cev_apd.capacity() - cev_apd.len() - 1) as isize
+        (cev_apd.capacity() - cev_apd.len()) as isize
     );
     assert_eq!(cev, []);
 
@@ -49,7 +49,7 @@ fn test_extra_append() {
     assert_eq!(cev.as_ptr(), NonNull::<u8>::dangling().as_ptr());
     assert_eq!(
         unsafe { cev_apd.as_ptr().offset_from(cev_apd.raw_ptr()) },
-        (cev_apd.capacity() - cev_apd.len() - 1) as isize
+        (cev_apd.capacity() - cev_apd.len()) as isize
     );
     assert_eq!(cev, []);
 
@@ -59,7 +59,7 @@ fn test_extra_append() {
     cev.append(&mut cev_apd);
     assert_eq!(
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - cev.len() - 1) as isize
+        (cev.capacity() - cev.len()) as isize
     );
     assert_eq!(cev_apd.as_ptr(), Cev::new().as_ptr());
     assert_eq!(cev, []);
@@ -134,7 +134,7 @@ fn test_extra_append() {
     );
     assert_eq!(
         unsafe { cev_apd.as_ptr().offset_from(cev_apd.raw_ptr()) },
-        (cev_apd.capacity() - 1) as isize
+        cev_apd.capacity() as isize
     );
     assert_eq!(cev, [1, 2, 3, 4, 5]);
 
@@ -172,7 +172,7 @@ fn test_extra_append() {
     );
     assert_eq!(
         unsafe { cev_apd.as_ptr().offset_from(cev_apd.raw_ptr()) },
-        (cev_apd.capacity() - cev_apd.len() - 1) as isize
+        (cev_apd.capacity() - cev_apd.len()) as isize
     );
     assert_eq!(cev, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
     assert_eq!(cev_apd, Cev::new());
@@ -213,7 +213,7 @@ fn test_extra_append() {
     assert_eq!(cev, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
     assert_eq!(
         unsafe { cev_apd.as_ptr().offset_from(cev_apd.raw_ptr()) },
-        (cev_apd.capacity() - cev_apd.len() - 1) as isize
+        (cev_apd.capacity() - cev_apd.len()) as isize
     );
 
     let mut cev: Cev<u8> = Cev::from([6, 7, 8, 9, 10]);
@@ -234,7 +234,7 @@ fn test_extra_append() {
     assert_eq!(cev, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
     assert_eq!(
         unsafe { cev_apd.as_ptr().offset_from(cev_apd.raw_ptr()) },
-        (cev_apd.capacity() - cev_apd.len() - 1) as isize
+        (cev_apd.capacity() - cev_apd.len()) as isize
     );
 
     let mut cev: Cev<u8> = Cev::from([1, 2, 3, 4, 5]);
@@ -331,7 +331,7 @@ fn test_extra_append() {
     );
     assert_eq!(
         unsafe { cev_apd.as_ptr().offset_from(cev_apd.raw_ptr()) },
-        (cev_apd.capacity() - cev_apd.len() - 1) as isize
+        (cev_apd.capacity() - cev_apd.len()) as isize
     );
     assert_eq!(cev, ["two", "one"]);
     assert_eq!(cev_apd, Cev::<String>::new());
@@ -361,7 +361,7 @@ fn test_extra_append() {
     );
     assert_eq!(
         unsafe { cev_apd.as_ptr().offset_from(cev_apd.raw_ptr()) },
-        (cev_apd.capacity() - cev_apd.len() - 1) as isize
+        (cev_apd.capacity() - cev_apd.len()) as isize
     );
     assert_eq!(cev, ["two", "one"]);
     assert_eq!(cev_apd, Cev::<String>::new());
@@ -391,7 +391,7 @@ fn test_extra_append() {
     );
     assert_eq!(
         unsafe { cev_apd.as_ptr().offset_from(cev_apd.raw_ptr()) },
-        (cev_apd.capacity() - cev_apd.len() - 1) as isize
+        (cev_apd.capacity() - cev_apd.len()) as isize
     );
     assert_eq!(cev, ["two", "one"]);
     assert_eq!(cev_apd, Cev::<String>::new());
@@ -421,7 +421,7 @@ fn test_extra_append() {
     );
     assert_eq!(
         unsafe { cev_apd.as_ptr().offset_from(cev_apd.raw_ptr()) },
-        (cev_apd.capacity() - cev_apd.len() - 1) as isize
+        (cev_apd.capacity() - cev_apd.len()) as isize
     );
     assert_eq!(cev, ["two", "one"]);
     assert_eq!(cev_apd, Cev::<String>::new());
@@ -451,7 +451,7 @@ fn test_extra_append() {
     );
     assert_eq!(
         unsafe { cev_apd.as_ptr().offset_from(cev_apd.raw_ptr()) },
-        (cev_apd.capacity() - cev_apd.len() - 1) as isize
+        (cev_apd.capacity() - cev_apd.len()) as isize
     );
     assert_eq!(cev, ["two", "one"]);
     assert_eq!(cev_apd, Cev::<String>::new());
@@ -481,7 +481,7 @@ fn test_extra_append() {
     );
     assert_eq!(
         unsafe { cev_apd.as_ptr().offset_from(cev_apd.raw_ptr()) },
-        (cev_apd.capacity() - cev_apd.len() - 1) as isize
+        (cev_apd.capacity() - cev_apd.len()) as isize
     );
     assert_eq!(cev, ["two", "one"]);
     assert_eq!(cev_apd, Cev::<String>::new());
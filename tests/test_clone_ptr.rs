@@ -16,5 +16,5 @@ fn test_clone_ptr() {
     let cev = Cev::<usize>::with_capacity(1);
     let cld = cev.clone();
     assert_eq!(cev, cld);
-    assert_eq!(unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) }, (cev.capacity() - 1) as isize);
+    assert_eq!(unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) }, cev.capacity() as isize);
 }
@@ -23,7 +23,7 @@ fn test_into_vec_ptr() {
     let vec = cev.into_vec();
     assert_eq!(vec.len(), 0);
     assert_eq!(vec.capacity(), 1);
-    assert_eq!(vec.as_ptr(), mov_ptr);
+    assert_eq!(mov_ptr, unsafe { raw_ptr.add(1) });
     assert_eq!(vec.as_ptr(), raw_ptr);
     assert_eq!(vec, []);
 
@@ -0,0 +1,148 @@
+#![cfg(feature = "std")]
+
+use cev::Cev;
+use std::io::{self, BufRead, IoSlice, Read, Write};
+use std::ptr::NonNull;
+
+fn test_ptr_pos<T>(cev: &Cev<T>) -> bool {
+    if cev.capacity() == 0 {
+        cev.as_ptr() == NonNull::<T>::dangling().as_ptr()
+    } else {
+        unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) == ((cev.capacity() - cev.len()) as isize) }
+    }
+}
+
+#[test]
+fn test_write_prepends_chunks() {
+    let mut cev = Cev::new();
+    assert_eq!(cev.write(b"").unwrap(), 0);
+    assert!(test_ptr_pos(&cev));
+
+    assert_eq!(cev.write(b"world").unwrap(), 5);
+    assert_eq!(cev.write(b", ").unwrap(), 2);
+    cev.write_all(b"Hello").unwrap();
+    cev.flush().unwrap();
+    assert_eq!(cev, *b"Hello, world");
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_write_vectored() {
+    let mut cev = Cev::with_capacity(4);
+    cev.write_all(b"end").unwrap();
+    let bufs = [
+        IoSlice::new(b"a"),
+        IoSlice::new(b""),
+        IoSlice::new(b"bc"),
+        IoSlice::new(b"def"),
+    ];
+    assert_eq!(cev.write_vectored(&bufs).unwrap(), 6);
+    assert_eq!(cev, *b"abcdefend");
+    assert!(test_ptr_pos(&cev));
+
+    assert_eq!(cev.write_vectored(&[]).unwrap(), 0);
+    assert_eq!(cev, *b"abcdefend");
+}
+
+#[test]
+fn test_write_fmt_and_copy() {
+    let mut cev = Cev::new();
+    write!(cev, "{}", 42).unwrap();
+    assert_eq!(cev, *b"42");
+
+    let mut cev = Cev::from(*b"tail");
+    let copied = io::copy(&mut &b"head:"[..], &mut cev).unwrap();
+    assert_eq!(copied, 5);
+    assert_eq!(cev, *b"head:tail");
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_write_trailer_first_encoder() {
+    fn encode<W: Write>(out: &mut W, fields: &[&[u8]]) -> io::Result<()> {
+        for field in fields.iter().rev() {
+            out.write_all(field)?;
+            out.write_all(&[field.len() as u8])?;
+        }
+        Ok(())
+    }
+
+    let mut cev = Cev::new();
+    encode(&mut cev, &[b"ab", b"cde"]).unwrap();
+    assert_eq!(cev, [2, b'a', b'b', 3, b'c', b'd', b'e']);
+}
+
+#[test]
+fn test_read_consumes_front() {
+    let mut cev = Cev::with_capacity(16);
+    cev.write_all(b"0123456789").unwrap();
+    let ptr = cev.as_ptr();
+
+    let mut buf = [0; 4];
+    assert_eq!(cev.read(&mut buf).unwrap(), 4);
+    assert_eq!(&buf, b"0123");
+    assert_eq!(cev, *b"456789");
+    assert_eq!(cev.as_ptr(), unsafe { ptr.add(4) });
+    assert!(test_ptr_pos(&cev));
+
+    let mut rest = Vec::new();
+    cev.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, b"456789");
+    assert!(cev.is_empty());
+    assert!(test_ptr_pos(&cev));
+    assert_eq!(cev.read(&mut buf).unwrap(), 0);
+    assert_eq!(cev.capacity(), 16);
+}
+
+#[test]
+fn test_buf_read() {
+    let mut cev = Cev::new();
+    cev.write_all(b"second\n").unwrap();
+    cev.write_all(b"first\n").unwrap();
+
+    assert_eq!(cev.fill_buf().unwrap(), b"first\nsecond\n");
+    cev.consume(1);
+    let lines: Vec<String> = (&mut cev).lines().map(Result::unwrap).collect();
+    assert_eq!(lines, ["irst", "second"]);
+    assert!(cev.is_empty());
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev = Cev::from(*b"ab");
+    cev.consume(10);
+    assert!(cev.is_empty());
+    assert!(test_ptr_pos(&cev));
+}
+
+#[test]
+fn test_fill_front_drain_front() {
+    let mut cev = Cev::with_capacity(8);
+    for round in 0..10u8 {
+        cev.write_all(&[round; 3]).unwrap();
+        let mut buf = [0; 3];
+        cev.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [round; 3]);
+    }
+    assert!(cev.is_empty());
+    assert_eq!(cev.capacity(), 8);
+}
+
+#[test]
+fn test_read_to_front() {
+    let mut cev = Cev::from(*b"data");
+    let mut source = io::Cursor::new(b"hdr2hdr1".to_vec());
+    cev.read_to_front(&mut source, 4).unwrap();
+    assert_eq!(cev, *b"hdr2data");
+    cev.read_to_front(&mut source, 0).unwrap();
+    cev.read_to_front(&mut source, 4).unwrap();
+    assert_eq!(cev, *b"hdr1hdr2data");
+    assert!(test_ptr_pos(&cev));
+
+    let err = cev.read_to_front(&mut source, 1).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    assert_eq!(cev, *b"hdr1hdr2data");
+    assert!(test_ptr_pos(&cev));
+
+    let mut cev: Cev<u8> = Cev::new();
+    cev.read_to_front(&mut io::repeat(7), 3).unwrap();
+    assert_eq!(cev, [7, 7, 7]);
+}
@@ -8,7 +8,7 @@ fn test_extra_pop() {
     assert_eq!(cev.pop(), Some(253));
     assert_eq!(
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - 1) as isize
+        cev.capacity() as isize
     );
 
     let mut cev: Cev<isize> = Cev::new();
@@ -59,11 +59,11 @@ fn test_extra_pop() {
     assert_eq!(cev.pop(), Some(8));
     assert_eq!(
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - 1) as isize
+        cev.capacity() as isize
     );
     assert_eq!(cev.pop(), None);
     assert_eq!(
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - 1) as isize
+        cev.capacity() as isize
     );
 }
@@ -20,7 +20,10 @@ fn test_set_len_ptr() {
     unsafe {
         cev.set_len_ptr(0);
     };
-    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+    assert_eq!(
+        unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
+        cev.capacity() as isize
+    );
     cev.reserve(8);
     cev.push(4);
     cev.push(3);
@@ -31,7 +34,7 @@ fn test_set_len_ptr() {
     };
     assert_eq!(
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - 1) as isize
+        cev.capacity() as isize
     );
     cev.push(8);
     cev.push(7);
@@ -47,7 +50,7 @@ fn test_set_len_ptr() {
     };
     assert_eq!(
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - 1) as isize
+        cev.capacity() as isize
     );
 
     let mut cev: Cev<()> = Cev::with_capacity(0);
@@ -79,36 +82,42 @@ fn test_set_len_ptr() {
     assert!(dangling_ptr_eq(&cev));
 
     let mut cev: Cev<Cev<u8>> = Cev::with_capacity(1);
-    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+    assert_eq!(
+        unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
+        cev.capacity() as isize
+    );
     unsafe {
         cev.set_len_ptr(0);
     };
-    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+    assert_eq!(
+        unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
+        cev.capacity() as isize
+    );
 
     let mut cev: Cev<Cev<u8>> = Cev::with_capacity(10);
     assert_eq!(
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - 1) as isize
+        cev.capacity() as isize
     );
     unsafe {
         cev.set_len_ptr(0);
     };
     assert_eq!(
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - 1) as isize
+        cev.capacity() as isize
     );
 
     let mut cev: Cev<u8> = Cev::with_capacity(4);
     assert_eq!(
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - 1) as isize
+        cev.capacity() as isize
     );
     unsafe {
         cev.set_len_ptr(0);
     };
     assert_eq!(
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - 1) as isize
+        cev.capacity() as isize
     );
     cev.push(4);
     cev.push(3);
@@ -120,6 +129,6 @@ fn test_set_len_ptr() {
     };
     assert_eq!(
         unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) },
-        (cev.capacity() - 1) as isize
+        cev.capacity() as isize
     );
 }
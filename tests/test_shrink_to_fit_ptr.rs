@@ -0,0 +1,80 @@
+use cev::Cev;
+use std::ptr::NonNull;
+
+#[test]
+fn test_shrink_to_fit_ptr() {
+    let mut cev: Cev<u8> = Cev::new();
+    cev.shrink_to_fit();
+    assert_eq!(cev.capacity(), 0);
+    assert_eq!(cev.as_ptr(), NonNull::<u8>::dangling().as_ptr());
+    assert_eq!(cev, []);
+
+    let mut cev: Cev<u8> = Cev::with_capacity(10);
+    cev.shrink_to_fit();
+    assert_eq!(cev.capacity(), 0);
+    assert_eq!(cev.as_ptr(), NonNull::<u8>::dangling().as_ptr());
+    assert_eq!(cev.raw_ptr(), NonNull::<u8>::dangling().as_ptr());
+    cev.push(1);
+    assert_eq!(cev, [1]);
+
+    let mut cev: Cev<u16> = Cev::with_capacity(10);
+    cev.push(3);
+    cev.push(2);
+    cev.push(1);
+    cev.shrink_to_fit();
+    assert_eq!(cev.capacity(), 3);
+    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+    assert_eq!(cev, [1, 2, 3]);
+    cev.push(0);
+    assert_eq!(unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) }, (cev.capacity() - cev.len()) as isize);
+    assert_eq!(cev, [0, 1, 2, 3]);
+
+    let mut cev: Cev<String> = (0..100).map(|i| i.to_string()).collect();
+    cev.truncate(2);
+    cev.shrink_to_fit();
+    assert_eq!(cev.capacity(), 2);
+    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+    assert_eq!(cev, ["98", "99"]);
+    cev.clear();
+    cev.shrink_to_fit();
+    assert_eq!(cev.capacity(), 0);
+    assert_eq!(cev.as_ptr(), NonNull::<String>::dangling().as_ptr());
+
+    let mut cev: Cev<()> = Cev::from([(), (), ()]);
+    cev.shrink_to_fit();
+    assert_eq!(cev.capacity(), usize::MAX);
+    assert_eq!(cev.as_ptr(), NonNull::<()>::dangling().as_ptr());
+    assert_eq!(cev, [(), (), ()]);
+}
+
+#[test]
+fn test_shrink_to_ptr() {
+    let mut cev: Cev<u32> = Cev::with_capacity(10);
+    cev.shrink_to(4);
+    assert_eq!(cev.capacity(), 4);
+    assert_eq!(cev.len(), 0);
+    assert_eq!(unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) }, cev.capacity() as isize);
+    cev.push(1);
+    assert_eq!(unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) }, (cev.capacity() - cev.len()) as isize);
+    assert_eq!(cev, [1]);
+
+    let mut cev: Cev<u32> = Cev::with_capacity(10);
+    cev.push(3);
+    cev.push(2);
+    cev.push(1);
+    cev.shrink_to(20);
+    assert_eq!(cev.capacity(), 10);
+    cev.shrink_to(5);
+    assert_eq!(cev.capacity(), 5);
+    assert_eq!(unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) }, (cev.capacity() - cev.len()) as isize);
+    assert_eq!(cev, [1, 2, 3]);
+    cev.shrink_to(0);
+    assert_eq!(cev.capacity(), 3);
+    assert_eq!(cev.as_ptr(), cev.raw_ptr());
+    assert_eq!(cev, [1, 2, 3]);
+
+    let mut cev: Cev<u32> = Cev::with_capacity(10);
+    cev.shrink_to(0);
+    assert_eq!(cev.capacity(), 0);
+    assert_eq!(cev.as_ptr(), NonNull::<u32>::dangling().as_ptr());
+}
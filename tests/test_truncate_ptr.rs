@@ -18,14 +18,14 @@ fn test_truncate_ptr() {
     let mut cev: Cev<u8> = Cev::with_capacity(1);
     cev.truncate(0);
     assert_eq!(cev.len(), 0);
-    assert_eq!( unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) }, (cev.capacity() - cev.len() - 1) as isize);
+    assert_eq!( unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) }, (cev.capacity() - cev.len()) as isize);
     assert_eq!(cev, []);
 
     let mut cev: Cev<u8> = Cev::new();
     cev.reserve(1);
     cev.truncate(0);
     assert_eq!(cev.len(), 0);
-    assert_eq!(unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) }, (cev.capacity() - cev.len() - 1) as isize);
+    assert_eq!(unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) }, (cev.capacity() - cev.len()) as isize);
     assert_eq!(cev, []);
 
     let mut cev: Cev<u8> = Cev::from([5, 4, 3, 2, 1]);
@@ -52,7 +52,7 @@ fn test_truncate_ptr() {
     cev.truncate(0);
     assert_eq!(cev.capacity(), cap);
     assert_eq!(cev.len(), 0);
-    assert_eq!(unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) }, (cev.capacity() - 1) as isize);
+    assert_eq!(unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) }, cev.capacity() as isize);
     assert_eq!(cev, []);
 
     let mut cev: Cev<()> = Cev::new();
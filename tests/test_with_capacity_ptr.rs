@@ -11,12 +11,12 @@ fn test_with_capacity_ptr() {
     let cev = Cev::<u64>::with_capacity(1);
     assert_eq!(cev.len(), 0);
     assert_eq!(cev.capacity(), 1);
-    assert_eq!(unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) }, (cev.capacity() - 1) as isize);
+    assert_eq!(unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) }, cev.capacity() as isize);
 
     let cev = Cev::<isize>::with_capacity(6);
     assert_eq!(cev.len(), 0);
     assert_eq!(cev.capacity(), 6);
-    assert_eq!(unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) }, (cev.capacity() - 1) as isize);
+    assert_eq!(unsafe { cev.as_ptr().offset_from(cev.raw_ptr()) }, cev.capacity() as isize);
 
     let cev = Cev::<()>::with_capacity(0);
     assert_eq!(cev.len(), 0);
@@ -0,0 +1,180 @@
+use cev::{Cev, ThinCev, TryReserveErrorKind};
+use core::mem::size_of;
+use std::rc::Rc;
+
+#[test]
+fn test_size() {
+    assert_eq!(size_of::<ThinCev<u8>>(), size_of::<usize>());
+    assert_eq!(size_of::<Option<ThinCev<u8>>>(), size_of::<usize>());
+}
+
+#[test]
+fn test_push_pop() {
+    let mut thin = ThinCev::new();
+    assert_eq!(thin.capacity(), 0);
+    assert_eq!(thin.pop(), None);
+    thin.clear();
+    thin.truncate(0);
+    assert_eq!(thin, []);
+
+    for i in 0..100 {
+        thin.push(i);
+    }
+    assert_eq!(thin.len(), 100);
+    assert!(thin.capacity() >= 100);
+    assert!(thin.iter().rev().copied().eq(0..100));
+
+    for i in (0..100).rev() {
+        assert_eq!(thin.pop(), Some(i));
+    }
+    assert!(thin.is_empty());
+    assert_eq!(thin.pop(), None);
+}
+
+#[test]
+fn test_insert_remove() {
+    let mut thin = ThinCev::new();
+    thin.insert(0, 'c');
+    thin.insert(0, 'a');
+    thin.insert(1, 'b');
+    thin.insert(3, 'd');
+    assert_eq!(thin, ['a', 'b', 'c', 'd']);
+
+    assert_eq!(thin.remove(2), 'c');
+    assert_eq!(thin.remove(0), 'a');
+    assert_eq!(thin, ['b', 'd']);
+}
+
+#[test]
+#[should_panic(expected = "insertion index (is 1) should be <= len (is 0)")]
+fn test_insert_out_of_bounds() {
+    let mut thin = ThinCev::new();
+    thin.insert(1, 0);
+}
+
+#[test]
+#[should_panic(expected = "removal index (is 0) should be < len (is 0)")]
+fn test_remove_empty() {
+    let mut thin: ThinCev<u8> = ThinCev::new();
+    thin.remove(0);
+}
+
+#[test]
+fn test_truncate_drop() {
+    let rc = Rc::new(());
+    let mut thin = ThinCev::new();
+    for _ in 0..6 {
+        thin.push(Rc::clone(&rc));
+    }
+    thin.truncate(2);
+    assert_eq!(thin.len(), 2);
+    assert_eq!(Rc::strong_count(&rc), 3);
+
+    let cloned = thin.clone();
+    assert_eq!(Rc::strong_count(&rc), 5);
+    drop(cloned);
+    thin.clear();
+    assert_eq!(Rc::strong_count(&rc), 1);
+    assert!(thin.capacity() >= 6);
+
+    thin.push(Rc::clone(&rc));
+    drop(thin);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_reserve() {
+    let mut thin: ThinCev<u32> = ThinCev::with_capacity(3);
+    assert_eq!(thin.capacity(), 3);
+    thin.push(1);
+    thin.reserve(10);
+    assert!(thin.capacity() >= 11);
+    assert_eq!(thin, [1]);
+
+    assert_eq!(
+        thin.try_reserve(usize::MAX).map_err(|e| e.kind()),
+        Err(TryReserveErrorKind::CapacityOverflow)
+    );
+    assert_eq!(
+        thin.try_reserve(isize::MAX as usize).map_err(|e| e.kind()),
+        Err(TryReserveErrorKind::CapacityOverflow)
+    );
+    assert_eq!(thin, [1]);
+}
+
+#[test]
+fn test_zst() {
+    let mut thin = ThinCev::new();
+    assert_eq!(thin.capacity(), 0);
+    for _ in 0..10 {
+        thin.push(());
+    }
+    assert_eq!(thin.len(), 10);
+    assert_eq!(thin.capacity(), usize::MAX);
+    assert_eq!(thin.pop(), Some(()));
+    thin.insert(9, ());
+    assert_eq!(thin.remove(0), ());
+    assert_eq!(thin.len(), 9);
+
+    let cev = Cev::from(thin);
+    assert_eq!(cev.len(), 9);
+    let thin = ThinCev::from(cev);
+    assert_eq!(thin.len(), 9);
+}
+
+#[test]
+fn test_over_aligned() {
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[repr(align(64))]
+    struct Aligned(u8);
+
+    let mut thin = ThinCev::new();
+    assert_eq!(thin.as_ptr() as usize % 64, 0);
+    for i in 0..20 {
+        thin.push(Aligned(i));
+        assert_eq!(thin.as_ptr() as usize % 64, 0);
+    }
+    assert_eq!(thin[0], Aligned(19));
+    assert_eq!(thin[19], Aligned(0));
+}
+
+#[test]
+fn test_cev_conversions() {
+    let mut cev = Cev::with_capacity(10);
+    cev.push(String::from("b"));
+    cev.push(String::from("a"));
+    let mut thin = ThinCev::from(cev);
+    assert_eq!(thin, ["a", "b"]);
+    assert_eq!(thin.capacity(), 2);
+    thin.push(String::from("_"));
+
+    let cev = Cev::from(thin);
+    assert_eq!(cev, ["_", "a", "b"]);
+    assert_eq!(cev.capacity(), 3);
+
+    let thin = ThinCev::from(Cev::<u8>::new());
+    assert_eq!(thin.capacity(), 0);
+    let cev = Cev::from(thin);
+    assert_eq!(cev.capacity(), 0);
+}
+
+#[test]
+fn test_traits() {
+    let thin = ThinCev::from(Cev::from([1, 2, 3]));
+    assert_eq!(thin, thin.clone());
+    assert_eq!(thin, &[1, 2, 3][..]);
+    assert_ne!(thin, ThinCev::default());
+    assert_eq!(format!("{thin:?}"), "[1, 2, 3]");
+    assert_eq!((&thin).into_iter().sum::<i32>(), 6);
+
+    let mut thin = thin;
+    for elem in &mut thin {
+        *elem *= 2;
+    }
+    assert_eq!(thin.as_ref(), [2, 4, 6]);
+
+    let other = ThinCev::from(Cev::from([2, 5]));
+    assert!(thin < other);
+    assert_eq!(thin.cmp(&other), std::cmp::Ordering::Less);
+    assert!(ThinCev::<i32>::new() < thin);
+}